@@ -0,0 +1,444 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{ext::IdentExt, Attribute, Data, DataStruct, GenericParam, Generics, Visibility};
+
+use super::{
+    build_default_initializer, build_instance, build_owned_value, collect_field_datas,
+    extract_fields, to_pascal_case, BuilderKind, DefaultValue, FieldData, MainProps,
+};
+
+pub(crate) fn derive_builder_impl(
+    ident: Ident,
+    visibility: Visibility,
+    data: Data,
+    generics: Generics,
+    attributes: Vec<Attribute>,
+) -> syn::Result<TokenStream> {
+    let Data::Struct(DataStruct { fields, .. }) = &data else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "'Builder' can only be derived for structs",
+        ));
+    };
+
+    let props = MainProps::from_attributes(&attributes, &visibility)?;
+
+    if let Some(validate) = &props.validate {
+        return Err(syn::Error::new_spanned(
+            validate,
+            "'validate' is not supported by 'Builder'",
+        ));
+    }
+
+    let (fields, is_named) = extract_fields(fields);
+    let fields = collect_field_datas(&fields, props.with)?;
+
+    for field in &fields {
+        if field.try_into {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'try_into' is not supported by 'Builder'",
+            ));
+        }
+
+        if let Some(validate) = &field.validate {
+            return Err(syn::Error::new_spanned(
+                validate,
+                "'validate' is not supported by 'Builder'",
+            ));
+        }
+
+        if matches!(field.default, DefaultValue::Computed(_)) {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'value' is not supported by 'Builder'",
+            ));
+        }
+
+        if field.name.unraw() == "build" && !is_automatic_default(&field.default) {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "The 'build' setter clashes with the generated 'build' method. Rename it with #[new(name = \"...\")].",
+            ));
+        }
+    }
+
+    let public = props.visibility.to_token_stream();
+
+    match props.builder {
+        BuilderKind::Runtime => Ok(generate_runtime_builder(
+            &ident, &generics, &fields, is_named, &public,
+        )),
+        BuilderKind::Typestate => Ok(generate_typestate_builder(
+            &ident, &generics, &fields, is_named, &public,
+        )),
+    }
+}
+
+fn generate_runtime_builder(
+    ident: &Ident,
+    generics: &Generics,
+    fields: &[FieldData],
+    is_named: bool,
+    public: &TokenStream,
+) -> TokenStream {
+    let builder_name = format_ident!("{}Builder", ident);
+    let error_name = format_ident!("{}BuilderError", ident);
+
+    let names: Vec<_> = fields.iter().map(|field| field.name.clone()).collect();
+    let types = fields.iter().map(|field| &field.field_type);
+    let setters = fields
+        .iter()
+        .filter(|field| !is_automatic_default(&field.default))
+        .map(|field| build_setter(field, public));
+    let values: Vec<_> = fields
+        .iter()
+        .map(|field| build_value(field, &error_name))
+        .collect();
+    let members: Vec<_> = fields.iter().map(|field| field.member.clone()).collect();
+    let instance = build_instance(&quote!(#ident), is_named, &members, &values);
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let builder_doc = format!(
+        "Builder for [`{}`], generated by `#[derive(Builder)]`.",
+        ident
+    );
+    let builder_fn_doc = format!("Creates a [`{}`] with no fields set.", builder_name);
+    let error = generate_builder_error(&error_name, public);
+
+    quote! {
+        #[doc = #builder_doc]
+        #[must_use]
+        #public struct #builder_name #impl_generics #where_clause {
+            #(#names: ::core::option::Option<#types>,)*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #ident #type_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #public fn builder() -> #builder_name #type_generics {
+                #builder_name {
+                    #(#names: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #builder_name #type_generics #where_clause {
+            #(#setters)*
+
+            /// Builds the value, failing if any required field has not been set.
+            #public fn build(self) -> ::core::result::Result<#ident #type_generics, #error_name> {
+                ::core::result::Result::Ok(#instance)
+            }
+        }
+
+        #error
+    }
+}
+
+fn generate_typestate_builder(
+    ident: &Ident,
+    generics: &Generics,
+    fields: &[FieldData],
+    is_named: bool,
+    public: &TokenStream,
+) -> TokenStream {
+    let builder_name = format_ident!("{}Builder", ident);
+
+    let parameters = generic_parameters(generics);
+    let arguments = generic_arguments(generics);
+    let where_clause = &generics.where_clause;
+    let (_, type_generics, _) = generics.split_for_impl();
+
+    let states: Vec<_> = fields
+        .iter()
+        .map(|field| matches!(field.default, DefaultValue::None).then(|| state_parameter(field)))
+        .collect();
+    let state_parameters: Vec<_> = states.iter().flatten().collect();
+
+    let names: Vec<_> = fields.iter().map(|field| field.name.clone()).collect();
+    let storage_types = fields.iter().zip(&states).map(|(field, state)| {
+        let field_type = &field.field_type;
+
+        match state {
+            Some(state) => quote!(#state),
+            None => quote!(::core::option::Option<#field_type>),
+        }
+    });
+    let initial_states = states.iter().map(|state| match state {
+        Some(_) => quote!(()),
+        None => quote!(::core::option::Option::None),
+    });
+    let unset_arguments = state_parameters.iter().map(|_| quote!(()));
+    let set_arguments = fields
+        .iter()
+        .filter(|field| matches!(field.default, DefaultValue::None))
+        .map(|field| {
+            let field_type = &field.field_type;
+            quote!((#field_type,))
+        });
+
+    let required_setters = fields
+        .iter()
+        .zip(&states)
+        .filter_map(|(field, state)| state.as_ref().map(|state| (field, state)))
+        .map(|(field, state)| {
+            let other_states = state_parameters.iter().filter(|other| **other != state);
+            let before = state_parameters.iter().map(|other| {
+                if *other == state {
+                    quote!(())
+                } else {
+                    quote!(#other)
+                }
+            });
+            let after = state_parameters.iter().map(|other| {
+                if *other == state {
+                    let field_type = &field.field_type;
+                    quote!((#field_type,))
+                } else {
+                    quote!(#other)
+                }
+            });
+            let moved = names.iter().map(|name| {
+                if *name == field.name {
+                    quote!(#name: (#name,))
+                } else {
+                    quote!(#name: self.#name)
+                }
+            });
+            let (parameter, value) = build_setter_parameter(field);
+            let name = &field.name;
+            let doc = format!("Sets the required `{}` field.", name.unraw());
+
+            quote! {
+                #[automatically_derived]
+                impl<#(#parameters,)* #(#other_states),*> #builder_name<#(#arguments,)* #(#before),*> #where_clause {
+                    #[doc = #doc]
+                    #public fn #name(self, #name: #parameter) -> #builder_name<#(#arguments,)* #(#after),*> {
+                        let #name = #value;
+
+                        #builder_name {
+                            #(#moved,)*
+                            __marker: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+            }
+        });
+
+    let optional_setters = fields
+        .iter()
+        .zip(&states)
+        .filter(|(field, state)| state.is_none() && !is_automatic_default(&field.default))
+        .map(|(field, _)| build_setter(field, public));
+
+    let values: Vec<_> = fields
+        .iter()
+        .zip(&states)
+        .map(|(field, state)| {
+            let name = &field.name;
+
+            if state.is_some() {
+                return quote!(self.#name.0);
+            }
+
+            let default = build_default_initializer(field, false);
+
+            quote! {
+                match self.#name {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => #default,
+                }
+            }
+        })
+        .collect();
+    let members: Vec<_> = fields.iter().map(|field| field.member.clone()).collect();
+    let instance = build_instance(&quote!(#ident), is_named, &members, &values);
+
+    let builder_doc = format!(
+        "Builder for [`{}`], generated by `#[derive(Builder)]`. \
+         `build()` is only available once every required field has been set.",
+        ident
+    );
+    let builder_fn_doc = format!("Creates a [`{}`] with no fields set.", builder_name);
+
+    quote! {
+        #[doc = #builder_doc]
+        #[must_use]
+        #public struct #builder_name<#(#parameters,)* #(#state_parameters),*> #where_clause {
+            #(#names: #storage_types,)*
+            __marker: ::core::marker::PhantomData<fn() -> #ident #type_generics>,
+        }
+
+        #[automatically_derived]
+        impl<#(#parameters),*> #ident #type_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #public fn builder() -> #builder_name<#(#arguments,)* #(#unset_arguments),*> {
+                #builder_name {
+                    #(#names: #initial_states,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #(#required_setters)*
+
+        #[automatically_derived]
+        impl<#(#parameters,)* #(#state_parameters),*> #builder_name<#(#arguments,)* #(#state_parameters),*> #where_clause {
+            #(#optional_setters)*
+        }
+
+        #[automatically_derived]
+        impl<#(#parameters),*> #builder_name<#(#arguments,)* #(#set_arguments),*> #where_clause {
+            /// Builds the value. Only available once every required field has been set.
+            #[must_use]
+            #public fn build(self) -> #ident #type_generics {
+                #instance
+            }
+        }
+    }
+}
+
+fn state_parameter(field: &FieldData) -> Ident {
+    format_ident!("__{}", to_pascal_case(&field.name))
+}
+
+fn generic_parameters(generics: &Generics) -> Vec<TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|parameter| match parameter {
+            GenericParam::Type(parameter) => {
+                let mut parameter = parameter.clone();
+                parameter.eq_token = None;
+                parameter.default = None;
+                parameter.to_token_stream()
+            }
+            GenericParam::Const(parameter) => {
+                let mut parameter = parameter.clone();
+                parameter.eq_token = None;
+                parameter.default = None;
+                parameter.to_token_stream()
+            }
+            GenericParam::Lifetime(parameter) => parameter.to_token_stream(),
+        })
+        .collect()
+}
+
+fn generic_arguments(generics: &Generics) -> Vec<TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|parameter| match parameter {
+            GenericParam::Type(parameter) => parameter.ident.to_token_stream(),
+            GenericParam::Const(parameter) => parameter.ident.to_token_stream(),
+            GenericParam::Lifetime(parameter) => parameter.lifetime.to_token_stream(),
+        })
+        .collect()
+}
+
+fn is_automatic_default(default: &DefaultValue) -> bool {
+    matches!(default, DefaultValue::Unit | DefaultValue::PhantomData)
+}
+
+fn build_setter(field: &FieldData, public: &TokenStream) -> TokenStream {
+    let name = &field.name;
+    let (parameter, value) = build_setter_parameter(field);
+    let doc = format!("Sets the `{}` field.", name.unraw());
+
+    quote! {
+        #[doc = #doc]
+        #public fn #name(mut self, #name: #parameter) -> Self {
+            self.#name = ::core::option::Option::Some(#value);
+            self
+        }
+    }
+}
+
+fn build_setter_parameter(field: &FieldData) -> (TokenStream, TokenStream) {
+    let FieldData {
+        name,
+        field_type,
+        into,
+        into_iter,
+        some,
+        to_owned,
+        ..
+    } = field;
+
+    if let Some(inner) = some {
+        return (quote!(#inner), quote!(::core::option::Option::Some(#name)));
+    }
+
+    if let Some(borrowed) = to_owned {
+        return (quote!(#borrowed), build_owned_value(field_type, name));
+    }
+
+    match (into, into_iter) {
+        (_, Some(item)) => (
+            quote!(impl ::core::iter::IntoIterator<Item = #item>),
+            quote!(::core::iter::Iterator::collect(
+                ::core::iter::IntoIterator::into_iter(#name)
+            )),
+        ),
+        (true, None) => (
+            quote!(impl ::core::convert::Into<#field_type>),
+            quote!(#name.into()),
+        ),
+        (false, None) => (quote!(#field_type), quote!(#name)),
+    }
+}
+
+fn build_value(field: &FieldData, error_name: &Ident) -> TokenStream {
+    let name = &field.name;
+    let field_name = name.unraw().to_string();
+
+    let missing = match build_default_initializer(field, false) {
+        Some(default) => default,
+        None => quote!(return ::core::result::Result::Err(#error_name { field: #field_name })),
+    };
+
+    quote! {
+        match self.#name {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => #missing,
+        }
+    }
+}
+
+fn generate_builder_error(error_name: &Ident, public: &TokenStream) -> TokenStream {
+    let error_impl = if cfg!(feature = "std") {
+        quote! {
+            #[automatically_derived]
+            impl ::std::error::Error for #error_name {}
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        /// Error returned by the builder when a required field has not been set.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #public struct #error_name {
+            field: &'static str,
+        }
+
+        #[automatically_derived]
+        impl #error_name {
+            /// Returns the name of the first required field that has not been set.
+            #public fn field(&self) -> &'static str {
+                self.field
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "missing required field `{}`", self.field)
+            }
+        }
+
+        #error_impl
+    }
+}
@@ -1,401 +1,3654 @@
 extern crate proc_macro;
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Delimiter, Ident, Spacing, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-    meta::ParseNestedMeta, parse_macro_input, punctuated::Punctuated, token::Comma, Attribute,
-    Data, DataStruct, DeriveInput, Error, Expr, Field, Fields, Generics, LitBool, LitStr, Token,
-    Type, TypePath, TypeTuple,
+    ext::IdentExt, meta::ParseNestedMeta, parenthesized, parse_macro_input, parse_quote,
+    punctuated::Punctuated, spanned::Spanned, token::Comma, visit::Visit, Attribute, Data,
+    DataEnum, DataStruct, DeriveInput, Error, Expr, ExprLit, Field, Fields, GenericArgument,
+    GenericParam, Generics, Index, Lifetime, Lit, LitBool, LitInt, LitStr, Member, Meta,
+    MetaNameValue, Path, PathArguments, Token, Type, TypePath, TypeSlice, TypeTuple, Variant,
+    Visibility,
 };
 
+mod builder;
+
 // Inspired by a part of SeaORM: https://github.com/SeaQL/sea-orm/blob/master/sea-orm-macros/src/derives/active_model.rs
 #[proc_macro_derive(New, attributes(new))]
 pub fn derive_new(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let DeriveInput {
         ident,
+        vis,
+        data,
+        generics,
+        attrs,
+    } = parse_macro_input!(input);
+
+    derive_new_impl(ident, vis, data, generics, attrs)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(Builder, attributes(new))]
+pub fn derive_builder(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let DeriveInput {
+        ident,
+        vis,
         data,
         generics,
         attrs,
-        ..
     } = parse_macro_input!(input);
 
-    derive_new_impl(ident, data, generics, attrs)
+    builder::derive_builder_impl(ident, vis, data, generics, attrs)
         .unwrap_or_else(Error::into_compile_error)
         .into()
 }
 
 fn derive_new_impl(
     ident: Ident,
+    visibility: Visibility,
     data: Data,
     generics: Generics,
     attributes: Vec<Attribute>,
 ) -> syn::Result<TokenStream> {
-    let Some((fields, is_named)) = extract_fields(&data) else {
+    let props = MainProps::from_attributes(&attributes, &visibility)?;
+
+    let constructors = match &data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            if props.constructors.is_empty() {
+                let new_name = props
+                    .rename
+                    .clone()
+                    .unwrap_or_else(|| Ident::new("new", Span::call_site()));
+                let constructor = ConstructorProps::from_main(new_name, &props);
+                let error_name = format_ident!("{}NewError", ident);
+                let args_name = constructor
+                    .args_struct
+                    .then(|| format_ident!("{}Args", ident));
+
+                vec![derive_constructor(
+                    quote!(Self),
+                    fields,
+                    &constructor,
+                    error_name,
+                    args_name,
+                    &generics,
+                    &props,
+                )?]
+            } else {
+                if props.rename.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        "'rename' and 'constructor' cannot be combined. Set the name inside each 'constructor' instead.",
+                    ));
+                }
+
+                props
+                    .constructors
+                    .iter()
+                    .map(|constructor| {
+                        let pascal_name = to_pascal_case(&constructor.name);
+                        let error_name = format_ident!("{}{}Error", ident, pascal_name);
+                        let args_name = constructor
+                            .args_struct
+                            .then(|| format_ident!("{}{}Args", ident, pascal_name));
+
+                        derive_constructor(
+                            quote!(Self),
+                            fields,
+                            constructor,
+                            error_name,
+                            args_name,
+                            &generics,
+                            &props,
+                        )
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?
+            }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            if props.rename.is_some() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "'rename' is not supported on enums. Use #[new(rename = \"...\")] on a variant instead.",
+                ));
+            }
+
+            if let Some(constructor) = props.constructors.first() {
+                return Err(syn::Error::new_spanned(
+                    &constructor.name,
+                    "'constructor' is not supported on enums.",
+                ));
+            }
+
+            if props.with {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "'with' is not supported on enums.",
+                ));
+            }
+
+            if props.args_struct {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "'args_struct' is not supported on enums.",
+                ));
+            }
+
+            if let Some(name) = props.order.first() {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "'order' is not supported on enums. Use #[new(position = ...)] on the fields instead.",
+                ));
+            }
+
+            variants
+                .iter()
+                .map(|variant| derive_variant_constructor(&ident, variant, &generics, &props))
+                .collect::<syn::Result<Vec<_>>>()?
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "'New' can only be derived for structs and enums",
+            ));
+        }
+    };
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let default_impl = if props.impl_default {
+        let default_name = find_default_constructor(&ident, &data, &constructors)?;
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::default::Default for #ident #type_generics #where_clause {
+                fn default() -> Self {
+                    Self::#default_name()
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let from_impl = if props.from {
+        let primary = find_from_constructor(&ident, &data, &constructors)?;
+        let name = &primary.name;
+        let (names, types): (Vec<_>, Vec<_>) = primary.parameters.iter().cloned().unzip();
+
+        let (source, pattern) = if let ([name], [source]) = (names.as_slice(), types.as_slice()) {
+            (quote!(#source), quote!(#name))
+        } else {
+            (quote!((#(#types,)*)), quote!((#(#names,)*)))
+        };
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#source> for #ident #type_generics #where_clause {
+                fn from(#pattern: #source) -> Self {
+                    Self::#name(#(#names),*)
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let withers = match &data {
+        Data::Struct(DataStruct { fields, .. }) => derive_withers(fields, &props)?,
+        _ => Vec::new(),
+    };
+
+    let methods = constructors.iter().map(|constructor| &constructor.method);
+    let items = constructors.iter().map(|constructor| &constructor.items);
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics #ident #type_generics #where_clause {
+            #(#methods)*
+
+            #(#withers)*
+        }
+
+        #(#items)*
+
+        #default_impl
+
+        #from_impl
+    ))
+}
+
+struct GeneratedConstructor {
+    name: Ident,
+    method: TokenStream,
+    items: TokenStream,
+    required: Vec<Ident>,
+    parameters: Vec<(Ident, Type)>,
+    fallible: bool,
+    returns_self: bool,
+}
+
+fn find_default_constructor<'a>(
+    ident: &Ident,
+    data: &Data,
+    constructors: &'a [GeneratedConstructor],
+) -> syn::Result<&'a Ident> {
+    if matches!(data, Data::Enum(_)) {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "'impl_default' is not supported on enums.",
+        ));
+    }
+
+    if let Some(constructor) = constructors.iter().find(|constructor| {
+        constructor.required.is_empty() && !constructor.fallible && constructor.returns_self
+    }) {
+        return Ok(&constructor.name);
+    }
+
+    let primary = &constructors[0];
+
+    if primary.required.is_empty() && !primary.returns_self {
+        return Err(syn::Error::new_spanned(
+            &primary.name,
+            "'impl_default' requires a constructor that returns Self. Use 'wrapped' instead of 'returns'.",
+        ));
+    }
+
+    if primary.required.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &primary.name,
+            "'impl_default' requires a constructor that cannot fail.",
+        ));
+    }
+
+    let error = primary
+        .required
+        .iter()
+        .map(|name| {
+            syn::Error::new_spanned(
+                name,
+                format!(
+                    "'impl_default' requires every field to have a default, but '{}' has none.",
+                    name.unraw()
+                ),
+            )
+        })
+        .reduce(|mut error, next| {
+            error.combine(next);
+            error
+        });
+
+    Err(error.unwrap_or_else(|| {
+        syn::Error::new_spanned(ident, "'impl_default' requires a constructor.")
+    }))
+}
+
+fn find_from_constructor<'a>(
+    ident: &Ident,
+    data: &Data,
+    constructors: &'a [GeneratedConstructor],
+) -> syn::Result<&'a GeneratedConstructor> {
+    if matches!(data, Data::Enum(_)) {
         return Err(syn::Error::new_spanned(
             ident,
-            "'New' can only be derived for structs",
+            "'from' is not supported on enums.",
+        ));
+    }
+
+    let primary = &constructors[0];
+
+    if primary.fallible {
+        return Err(syn::Error::new_spanned(
+            &primary.name,
+            "'from' requires a constructor that cannot fail.",
+        ));
+    }
+
+    if !primary.returns_self {
+        return Err(syn::Error::new_spanned(
+            &primary.name,
+            "'from' requires a constructor that returns Self. Use 'wrapped' instead of 'returns'.",
+        ));
+    }
+
+    if primary.parameters.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &primary.name,
+            "'from' requires a constructor with at least one parameter.",
         ));
+    }
+
+    let error = primary
+        .parameters
+        .iter()
+        .filter(|(_, parameter_type)| matches!(parameter_type, Type::ImplTrait(_)))
+        .map(|(name, _)| {
+            syn::Error::new_spanned(
+                name,
+                format!(
+                    "'from' can't be used because '{}' takes an `impl` parameter. Remove 'into' or 'into_iter' from it.",
+                    name.unraw()
+                ),
+            )
+        })
+        .reduce(|mut error, next| {
+            error.combine(next);
+            error
+        });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(primary),
+    }
+}
+
+fn derive_variant_constructor(
+    ident: &Ident,
+    variant: &Variant,
+    generics: &Generics,
+    props: &MainProps,
+) -> syn::Result<GeneratedConstructor> {
+    let variant_props = VariantProps::from_attributes(&variant.attrs)?;
+    let variant_name = &variant.ident;
+
+    let (fields, _) = extract_fields(&variant.fields);
+    if let Some(field) = collect_field_datas(&fields, false)?
+        .into_iter()
+        .find(|field| field.with)
+    {
+        let message = if field.some.is_some() && !matches!(field.default, DefaultValue::None) {
+            "'optional' is not supported on enums, since it implies 'with'. Use 'some' instead."
+        } else {
+            "'with' is not supported on enums."
+        };
+
+        return Err(syn::Error::new_spanned(field.name, message));
+    }
+    let new_name = variant_props
+        .rename
+        .unwrap_or_else(|| format_ident!("new_{}", to_snake_case(&variant_name.to_string())));
+
+    let constructor = ConstructorProps::from_main(new_name, props);
+    let error_name = format_ident!("{}{}NewError", ident, variant_name);
+
+    derive_constructor(
+        quote!(Self::#variant_name),
+        &variant.fields,
+        &constructor,
+        error_name,
+        None,
+        generics,
+        props,
+    )
+}
+
+fn derive_constructor(
+    target: TokenStream,
+    fields: &Fields,
+    constructor: &ConstructorProps,
+    error_name: Ident,
+    args_name: Option<Ident>,
+    generics: &Generics,
+    props: &MainProps,
+) -> syn::Result<GeneratedConstructor> {
+    let (fields, is_named) = extract_fields(fields);
+    let new_name = constructor.name.clone();
+
+    let mut field_datas = collect_field_datas(&fields, props.with)?;
+    let order = resolve_parameter_order(&field_datas, &props.order)?;
+    apply_constructor_defaults(&mut field_datas, constructor)?;
+
+    let resolved;
+    let constructor = if constructor.auto_constant {
+        resolved = ConstructorProps {
+            constant: supports_const(&field_datas, constructor, props),
+            ..constructor.clone()
+        };
+        &resolved
+    } else {
+        constructor
     };
 
-    let props = MainProps::from_attributes(&attributes)?;
-    let new_name = props.rename;
-    let public = if props.public { quote!(pub) } else { quote!() };
-    let constant = if props.constant {
+    let public = constructor.visibility.to_token_stream();
+    let constant = if constructor.constant {
         quote!(const)
     } else {
         quote!()
     };
 
-    let fields_with_types_and_settings = collect_field_datas(&fields)?;
+    let has_validators =
+        props.validate.is_some() || field_datas.iter().any(|field| field.validate.is_some());
 
-    if props.constant {
-        for field in &fields_with_types_and_settings {
-            if field.into {
-                return Err(syn::Error::new_spanned(
-                    &field.name,
-                    "'into' is not allowed in const constructors",
-                ));
-            }
+    if constructor.constant {
+        check_const_constructor(&mut field_datas, constructor, generics, props)?;
+    }
 
-            if matches!(field.default, DefaultValue::Trait) {
-                return Err(syn::Error::new_spanned(
-                    &field.name,
-                    "Default::default() is not allowed in const constructors",
-                ));
+    if constructor.args_struct {
+        if !is_named && !fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &new_name,
+                "'args_struct' requires a struct with named fields.",
+            ));
+        }
+
+        if let Some(field) = field_datas.iter().find(|field| field.try_into) {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'try_into' cannot be combined with 'args_struct'.",
+            ));
+        }
+    }
+
+    let failure = resolve_failure(
+        props,
+        has_validators,
+        &field_datas,
+        &new_name,
+        error_name,
+        &public,
+    )?;
+
+    let mut required: Vec<_> = field_datas
+        .iter()
+        .filter(|field| matches!(field.default, DefaultValue::None))
+        .map(|field| field.name.clone())
+        .chain(constructor.params.iter().map(|(name, _)| name.clone()))
+        .collect();
+    let conversions = build_conversion_parameters(&field_datas);
+
+    let wrap_error = failure
+        .as_ref()
+        .and_then(|failure| failure.wrap_error.as_ref());
+    let mut fields = build_constructor_fields(field_datas, constructor.constant, wrap_error);
+    let has_computed = fields.iter().any(|field| field.computed);
+    let has_params = !constructor.params.is_empty();
+
+    let mut constructor_field = build_parameter_list(&fields, order.as_deref(), constructor);
+    let documented = document_parameters(&constructor_field, &fields);
+    let default_docs: Vec<_> = fields
+        .iter()
+        .filter_map(|field| Some((field.name.clone(), field.default_doc.clone()?)))
+        .collect();
+
+    let mut documented_arguments = documented.clone();
+    let mut args_items = quote!();
+    let mut destructure = quote!();
+
+    if let Some(args_name) = &args_name {
+        let generated = generate_args_struct(
+            args_name,
+            constructor,
+            generics,
+            &mut fields,
+            documented,
+            constructor_field,
+        );
+
+        documented_arguments = vec![generated.documented];
+        constructor_field = vec![generated.parameter];
+        destructure = generated.destructure;
+        args_items = generated.items;
+        required.push(format_ident!("args"));
+    }
+
+    let doc = build_doc_attributes(&build_constructor_doc(
+        &build_constructor_summary(&target, constructor),
+        &documented_arguments,
+        &default_docs,
+        failure
+            .as_ref()
+            .map(|_| describe_errors(has_validators, !conversions.is_empty())),
+        constructor.constant,
+    ));
+    let forwarded = &constructor.attributes;
+    let attributes = quote!(#doc #(#[#forwarded])*);
+
+    let members: Vec<_> = fields.iter().map(|field| field.member.clone()).collect();
+    let bound: Vec<_> = fields
+        .iter()
+        .map(|field| match has_computed {
+            true => field.name.clone(),
+            false => field.hidden.clone(),
+        })
+        .collect();
+    let bindings = build_bindings(&fields, &constructor.params);
+
+    let fallible = failure.is_some();
+    let (instance, signature, items) = match failure {
+        None => {
+            let instance = if has_computed || has_params {
+                build_bound_instance(&target, is_named, &members, &bound, bindings)
+            } else {
+                let values: Vec<_> = fields.iter().map(|field| field.value.clone()).collect();
+                build_instance(&target, is_named, &members, &values)
+            };
+            let instance = quote!(#destructure #instance);
+            let instance = match &constructor.returns {
+                Some(returns) => returns.wrap(quote!({ #instance })),
+                None => instance,
+            };
+
+            (instance, None, args_items)
+        }
+        Some(failure) => {
+            let checks = build_field_checks(&fields, &bound);
+            let instance = build_fallible_instance(
+                &target,
+                is_named,
+                &members,
+                &bound,
+                quote!(#bindings #(#checks)*),
+                props.validate.as_ref(),
+                constructor.returns.as_ref(),
+            );
+            let signature = Signature {
+                error: failure.error,
+                generic_params: conversions,
+                predicates: failure.predicates,
+            };
+            let items = failure.items;
+
+            (
+                quote!(#destructure #instance),
+                Some(signature),
+                quote!(#items #args_items),
+            )
+        }
+    };
+
+    let must_use = (!fallible
+        && constructor.must_use
+        && !forwarded
+            .iter()
+            .any(|attribute| attribute.path().is_ident("must_use")))
+    .then(|| quote!(#[must_use]));
+    let output = constructor
+        .returns
+        .as_ref()
+        .map_or_else(|| quote!(Self), ReturnWrapper::output);
+    let wrapped = constructor.wrapped.as_ref().map(|wrapped| {
+        generate_wrapped_constructor(wrapped, &constructor_field, constructor, signature.as_ref())
+    });
+    let method = generate_constructor(
+        instance,
+        &constructor_field,
+        new_name.clone(),
+        quote!(#attributes #must_use),
+        quote!(#public #constant),
+        output,
+        signature,
+    );
+
+    Ok(GeneratedConstructor {
+        name: new_name,
+        method: quote!(#method #wrapped),
+        items,
+        required,
+        parameters: constructor_field,
+        fallible,
+        returns_self: constructor.returns.is_none(),
+    })
+}
+
+fn build_parameter_list(
+    fields: &[ConstructorField],
+    order: Option<&[Ident]>,
+    constructor: &ConstructorProps,
+) -> Vec<(Ident, Type)> {
+    let mut parameters: Vec<_> = fields
+        .iter()
+        .filter_map(|field| Some((field.hidden.clone(), field.parameter.clone()?)))
+        .collect();
+    if let Some(order) = order {
+        parameters.sort_by_key(|(name, _)| order.iter().position(|ordered| ordered == name));
+    }
+    parameters.extend(
+        constructor
+            .params
+            .iter()
+            .map(|(name, param_type)| (hide_parameter(name), param_type.clone())),
+    );
+
+    parameters
+}
+
+fn document_parameters(
+    parameters: &[(Ident, Type)],
+    fields: &[ConstructorField],
+) -> Vec<(Ident, Vec<String>)> {
+    parameters
+        .iter()
+        .map(|(name, _)| {
+            let docs = fields
+                .iter()
+                .find(|field| field.hidden == *name)
+                .map(|field| field.docs.clone())
+                .unwrap_or_default();
+
+            (name.clone(), docs)
+        })
+        .collect()
+}
+
+fn check_const_constructor(
+    fields: &mut [FieldData],
+    constructor: &ConstructorProps,
+    generics: &Generics,
+    props: &MainProps,
+) -> syn::Result<()> {
+    let mut default_errors: Vec<syn::Error> = Vec::new();
+
+    for field in fields {
+        let is_parameter = matches!(field.default, DefaultValue::None);
+
+        if is_parameter && field.into {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'into' is not allowed in const constructors",
+            ));
+        }
+
+        if field.try_into {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'try_into' is not allowed in const constructors",
+            ));
+        }
+
+        if is_parameter && field.into_iter.is_some() {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'into_iter' is not allowed in const constructors",
+            ));
+        }
+
+        if field.to_owned.is_some() {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'to_owned' is not allowed in const constructors",
+            ));
+        }
+
+        if matches!(field.default, DefaultValue::Trait) {
+            match const_default(&field.field_type) {
+                Some(default) => field.default = DefaultValue::CustomFunction(default),
+                None => default_errors.push(const_default_error(field, generics)),
             }
         }
+
+        if let Some(validate) = &field.validate {
+            return Err(syn::Error::new_spanned(
+                validate,
+                "'validate' is not allowed in const constructors",
+            ));
+        }
+
+        if field.from_fn {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                "'from_fn' is not allowed in const constructors. Use 'repeat' instead.",
+            ));
+        }
+    }
+
+    if let Some(error) = default_errors.into_iter().reduce(|mut error, next| {
+        error.combine(next);
+        error
+    }) {
+        return Err(error);
+    }
+
+    if let Some(validate) = &props.validate {
+        return Err(syn::Error::new_spanned(
+            validate,
+            "'validate' is not allowed in const constructors",
+        ));
+    }
+
+    if let Some(returns) = &constructor.returns {
+        return Err(syn::Error::new(
+            returns.span,
+            "'returns' is not allowed in const constructors. Use 'wrapped' to keep a const constructor that returns Self.",
+        ));
+    }
+
+    Ok(())
+}
+
+fn build_constructor_summary(target: &TokenStream, constructor: &ConstructorProps) -> String {
+    let mut summary = format!("Creates a new [`{}`].", target.to_string().replace(' ', ""));
+    if let Some(doc) = &constructor.doc {
+        summary = format!("{}\n\n{}", doc, summary);
+    }
+
+    if let Some(returns) = &constructor.returns {
+        summary.push_str(&format!(
+            "\n\nThe value is returned in {}.",
+            returns.describe()
+        ));
+    }
+
+    summary
+}
+
+fn describe_errors(has_validators: bool, converts: bool) -> &'static str {
+    match (has_validators, converts) {
+        (true, true) => "Returns an error if an argument can't be converted or fails validation.",
+        (true, false) => "Returns an error if validation fails.",
+        _ => "Returns an error if an argument can't be converted.",
+    }
+}
+
+struct ArgsStruct {
+    items: TokenStream,
+    destructure: TokenStream,
+    parameter: (Ident, Type),
+    documented: (Ident, Vec<String>),
+}
+
+struct Argument {
+    name: Ident,
+    argument_type: Type,
+    value: TokenStream,
+    docs: Vec<String>,
+}
+
+// Moves the constructor parameters into a generated struct. The conversions run in the
+// struct's `new`, so the constructor destructures the struct and takes the values as they are.
+fn generate_args_struct(
+    args_name: &Ident,
+    constructor: &ConstructorProps,
+    generics: &Generics,
+    fields: &mut [ConstructorField],
+    documented: Vec<(Ident, Vec<String>)>,
+    constructor_field: Vec<(Ident, Type)>,
+) -> ArgsStruct {
+    let mut arguments = Vec::new();
+    let mut bindings = Vec::new();
+
+    for field in fields.iter_mut().filter(|field| field.parameter.is_some()) {
+        let (name, hidden) = (&field.name, &field.hidden);
+        arguments.push(Argument {
+            name: name.clone(),
+            argument_type: field.field_type.clone(),
+            value: field.value.clone(),
+            docs: field.docs.clone(),
+        });
+        bindings.push(quote!(#name: #hidden));
+        field.value = quote!(#hidden);
     }
 
-    let defaults = build_default_initializers(&fields_with_types_and_settings, is_named);
+    for (name, param_type) in &constructor.params {
+        let hidden = hide_parameter(name);
+        arguments.push(Argument {
+            name: name.clone(),
+            argument_type: param_type.clone(),
+            value: quote!(#hidden),
+            docs: Vec::new(),
+        });
+        bindings.push(quote!(#name: #hidden));
+    }
 
-    let (constructor_field, pass_value) =
-        build_constructor_arguments(fields_with_types_and_settings, defaults, is_named);
+    let public = &constructor.visibility;
+    let constant = constructor.constant.then(|| quote!(const));
+    let constructor_field = constructor_field
+        .iter()
+        .map(|(name, parameter_type)| quote!(#name: #parameter_type));
+    let argument_types: Vec<_> = arguments
+        .iter()
+        .map(|argument| &argument.argument_type)
+        .collect();
+    let generics = used_generics(generics, &argument_types);
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
-    let constructor = generate_constructor(
-        is_named,
-        constructor_field,
-        pass_value,
-        new_name,
-        public,
-        constant,
+
+    let names = arguments.iter().map(|argument| &argument.name);
+    let values = arguments.iter().map(|argument| {
+        let Argument { name, value, .. } = argument;
+        quote!(#name: #value)
+    });
+    let field_docs = arguments.iter().map(|argument| {
+        let lines = if argument.docs.is_empty() {
+            vec![format!("The `{}` argument.", argument.name.unraw())]
+        } else {
+            argument.docs.clone()
+        };
+
+        build_doc_attributes(&lines)
+    });
+
+    let args_doc = format!(
+        "Arguments for `{}`, generated by `#[derive(New)]`.",
+        constructor.name.unraw()
     );
+    let new_doc = build_doc_attributes(&build_constructor_doc(
+        "Creates the arguments, applying the same conversions as the positional constructor.",
+        &documented,
+        &[],
+        None,
+        constructor.constant,
+    ));
+
+    let items = quote! {
+        #[doc = #args_doc]
+        #public struct #args_name #impl_generics #where_clause {
+            #(#field_docs #public #names: #argument_types,)*
+        }
 
-    Ok(quote!(
         #[automatically_derived]
-        impl #impl_generics #ident #type_generics #where_clause {
-            #constructor
+        impl #impl_generics #args_name #type_generics #where_clause {
+            #new_doc
+            #[must_use]
+            #public #constant fn new(#(#constructor_field),*) -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
         }
-    ))
+    };
+
+    let args = hide_parameter(&format_ident!("args"));
+
+    ArgsStruct {
+        items,
+        destructure: quote!(let #args_name { #(#bindings),* } = #args;),
+        parameter: (args.clone(), parse_quote!(#args_name #type_generics)),
+        documented: (
+            args,
+            vec![format!("The constructor arguments, see [`{}`].", args_name)],
+        ),
+    }
+}
+
+fn used_generics(generics: &Generics, types: &[&Type]) -> Generics {
+    let mut mentioned = GenericUsage::default();
+    for ty in types {
+        mentioned.visit_type(ty);
+    }
+
+    let (used, unused): (Vec<_>, Vec<_>) = generics
+        .params
+        .iter()
+        .cloned()
+        .partition(|parameter| mentioned.contains(parameter));
+
+    let where_clause = generics.where_clause.as_ref().map(|where_clause| {
+        let mut where_clause = where_clause.clone();
+        where_clause.predicates = where_clause
+            .predicates
+            .into_iter()
+            .filter(|predicate| {
+                let mut usage = GenericUsage::default();
+                usage.visit_where_predicate(predicate);
+                !unused.iter().any(|parameter| usage.contains(parameter))
+            })
+            .collect();
+        where_clause
+    });
+
+    Generics {
+        lt_token: generics.lt_token,
+        params: used.into_iter().collect(),
+        gt_token: generics.gt_token,
+        where_clause,
+    }
+}
+
+// Collects the names a type could refer to generic parameters by: the first segment of
+// unqualified paths, and lifetimes.
+#[derive(Default)]
+struct GenericUsage {
+    idents: Vec<Ident>,
+    lifetimes: Vec<Lifetime>,
 }
 
-fn extract_fields(data: &Data) -> Option<(Punctuated<Field, Comma>, bool)> {
-    match &data {
-        Data::Struct(DataStruct { fields, .. }) => match fields {
-            Fields::Named(found) => Some((found.named.clone(), true)),
-            Fields::Unnamed(found) => Some((found.unnamed.clone(), false)),
-            Fields::Unit => Some(Default::default()),
-        },
-        _ => None,
+impl GenericUsage {
+    fn contains(&self, parameter: &GenericParam) -> bool {
+        match parameter {
+            GenericParam::Type(parameter) => self.idents.contains(&parameter.ident),
+            GenericParam::Const(parameter) => self.idents.contains(&parameter.ident),
+            GenericParam::Lifetime(parameter) => self.lifetimes.contains(&parameter.lifetime),
+        }
+    }
+
+    fn push_path(&mut self, qself: Option<&syn::QSelf>, path: &Path) {
+        if qself.is_none() && path.leading_colon.is_none() {
+            if let Some(first) = path.segments.first() {
+                self.idents.push(first.ident.clone());
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for GenericUsage {
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        self.push_path(type_path.qself.as_ref(), &type_path.path);
+        syn::visit::visit_type_path(self, type_path);
+    }
+
+    fn visit_expr_path(&mut self, expr_path: &'ast syn::ExprPath) {
+        self.push_path(expr_path.qself.as_ref(), &expr_path.path);
+        syn::visit::visit_expr_path(self, expr_path);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.lifetimes.push(lifetime.clone());
+    }
+}
+
+fn supports_const(fields: &[FieldData], constructor: &ConstructorProps, props: &MainProps) -> bool {
+    let locals: Vec<(&Ident, &Type)> = fields
+        .iter()
+        .map(|field| (&field.name, &field.field_type))
+        .chain(constructor.params.iter().map(|(name, ty)| (name, ty)))
+        .collect();
+    let scope = ConstScope {
+        locals: locals.iter().map(|(name, _)| *name).collect(),
+        primitives: locals
+            .iter()
+            .filter(|(_, ty)| is_primitive(ty))
+            .map(|(name, _)| *name)
+            .collect(),
+    };
+
+    props.validate.is_none()
+        && constructor.returns.is_none()
+        && fields.iter().all(|field| {
+            let converts_parameter = matches!(field.default, DefaultValue::None)
+                && (field.into || field.into_iter.is_some());
+
+            !converts_parameter
+                && !field.try_into
+                && field.to_owned.is_none()
+                && field.validate.is_none()
+                && match &field.default {
+                    DefaultValue::Trait => const_default(&field.field_type).is_some(),
+                    DefaultValue::CustomFunction(expression)
+                    | DefaultValue::Computed(expression)
+                    | DefaultValue::Repeat {
+                        element: expression,
+                        ..
+                    } => syn::parse2(expression.clone())
+                        .is_ok_and(|expression| is_const_expression(&expression, &scope)),
+                    DefaultValue::None | DefaultValue::Unit | DefaultValue::PhantomData => true,
+                }
+        })
+}
+
+// The names visible to default and computed expressions. Operators are only const for
+// primitive operands, so the primitive ones are tracked separately.
+struct ConstScope<'a> {
+    locals: Vec<&'a Ident>,
+    primitives: Vec<&'a Ident>,
+}
+
+fn is_const_expression(expression: &Expr, scope: &ConstScope) -> bool {
+    let is_const = |expression: &Expr| is_const_expression(expression, scope);
+
+    match expression {
+        Expr::Lit(_) | Expr::Const(_) => true,
+        Expr::Path(path) => path.qself.is_none() && is_const_path(&path.path, scope),
+        Expr::Paren(paren) => is_const(&paren.expr),
+        Expr::Group(group) => is_const(&group.expr),
+        Expr::Unary(_) | Expr::Binary(_) => is_primitive_expression(expression, scope),
+        Expr::Cast(cast) => is_const(&cast.expr),
+        Expr::Field(field) => is_const(&field.base),
+        Expr::Reference(reference) => reference.mutability.is_none() && is_const(&reference.expr),
+        Expr::Repeat(repeat) => is_const(&repeat.expr),
+        Expr::Array(array) => array.elems.iter().all(is_const),
+        Expr::Tuple(tuple) => tuple.elems.iter().all(is_const),
+        Expr::Struct(structure) => {
+            structure.rest.is_none() && structure.fields.iter().all(|field| is_const(&field.expr))
+        }
+        Expr::Call(call) => {
+            let Expr::Path(function) = call.func.as_ref() else {
+                return false;
+            };
+
+            let is_constructor = function.path.segments.last().is_some_and(|segment| {
+                segment
+                    .ident
+                    .to_string()
+                    .starts_with(|first: char| first.is_ascii_uppercase())
+            });
+
+            is_constructor && call.args.iter().all(is_const)
+        }
+        _ => false,
+    }
+}
+
+// Constants and statics can't be told apart by their paths, and statics can't be read in
+// const functions, so only locals, primitive constants like `u32::MAX` and enum variants or
+// unit structs like `None` are trusted.
+fn is_const_path(path: &Path, scope: &ConstScope) -> bool {
+    if let Some(ident) = path.get_ident() {
+        if scope.locals.contains(&ident) {
+            return true;
+        }
+    }
+
+    let is_primitive_constant = path.leading_colon.is_none()
+        && path.segments.len() == 2
+        && is_primitive_name(&path.segments[0].ident);
+    let is_constructor = path.segments.last().is_some_and(|segment| {
+        let name = segment.ident.to_string();
+        name.starts_with(|first: char| first.is_ascii_uppercase())
+            && name.contains(|c: char| c.is_ascii_lowercase())
+    });
+
+    is_primitive_constant || is_constructor
+}
+
+// Whether the expression only applies built-in operators to primitive values, since operators on
+// other types go through traits, which can't be called in const contexts.
+fn is_primitive_expression(expression: &Expr, scope: &ConstScope) -> bool {
+    let is_primitive_operand = |expression: &Expr| is_primitive_expression(expression, scope);
+
+    match expression {
+        Expr::Lit(ExprLit { lit, .. }) => matches!(
+            lit,
+            Lit::Int(_) | Lit::Float(_) | Lit::Bool(_) | Lit::Char(_) | Lit::Byte(_)
+        ),
+        Expr::Path(path) => {
+            path.qself.is_none()
+                && match path.path.get_ident() {
+                    Some(ident) => scope.primitives.contains(&ident),
+                    // Associated constants of primitives, like `u32::MAX`.
+                    None => {
+                        path.path.segments.len() == 2
+                            && is_primitive_name(&path.path.segments[0].ident)
+                    }
+                }
+        }
+        Expr::Paren(paren) => is_primitive_operand(&paren.expr),
+        Expr::Group(group) => is_primitive_operand(&group.expr),
+        Expr::Cast(cast) => is_primitive(&cast.ty) && is_const_expression(&cast.expr, scope),
+        Expr::Unary(unary) => {
+            !matches!(unary.op, syn::UnOp::Deref(_)) && is_primitive_operand(&unary.expr)
+        }
+        Expr::Binary(binary) => {
+            is_primitive_operand(&binary.left) && is_primitive_operand(&binary.right)
+        }
+        _ => false,
+    }
+}
+
+fn is_primitive(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            path.get_ident().is_some_and(is_primitive_name)
+        }
+        _ => false,
+    }
+}
+
+fn is_primitive_name(ident: &Ident) -> bool {
+    matches!(
+        ident.to_string().as_str(),
+        "u8" | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "f32"
+            | "f64"
+            | "bool"
+            | "char"
+    )
+}
+
+fn const_default_error(field: &FieldData, generics: &Generics) -> syn::Error {
+    let mut mentioned = GenericUsage::default();
+    mentioned.visit_type(&field.field_type);
+
+    let generic = generics
+        .type_params()
+        .find(|parameter| mentioned.idents.contains(&parameter.ident));
+
+    let Some(generic) = generic else {
+        return syn::Error::new_spanned(
+            &field.name,
+            "Default::default() is not allowed in const constructors",
+        );
+    };
+
+    syn::Error::new_spanned(
+        &field.field_type,
+        format!(
+            "Default::default() is not allowed in const constructors, and the type of '{}' depends on the generic parameter '{}'. Use 'default = expression' with a const expression instead.",
+            field.name.unraw(),
+            generic.ident
+        ),
+    )
+}
+
+fn derive_withers(fields: &Fields, props: &MainProps) -> syn::Result<Vec<TokenStream>> {
+    let (fields, _) = extract_fields(fields);
+    let public = &props.visibility;
+
+    let withers = collect_field_datas(&fields, props.with)?
+        .into_iter()
+        .filter(|field| field.with)
+        .map(|field| {
+            let FieldData {
+                name: field_name,
+                member,
+                field_type,
+                default_doc,
+                docs,
+                into,
+                into_iter,
+                some,
+                ..
+            } = field;
+
+            let name = match &member {
+                Member::Unnamed(index) if field_name == format!("_{}", index.index) => {
+                    format_ident!("with_{}", index.index)
+                }
+                _ => format_ident!("with_{}", field_name.unraw()),
+            };
+            let constant = ((props.constant || props.auto_constant)
+                && !into
+                && into_iter.is_none()
+                && is_trivially_dropped(&field_type))
+            .then(|| quote!(const));
+
+            let (parameter, value) = match (some, into, into_iter) {
+                (Some(inner), _, _) => {
+                    (quote!(#inner), quote!(::core::option::Option::Some(value)))
+                }
+                (None, _, Some(item)) => (
+                    quote!(impl ::core::iter::IntoIterator<Item = #item>),
+                    quote!(::core::iter::Iterator::collect(
+                        ::core::iter::IntoIterator::into_iter(value)
+                    )),
+                ),
+                (None, true, None) => (
+                    quote!(impl ::core::convert::Into<#field_type>),
+                    quote!(value.into()),
+                ),
+                (None, false, None) => (quote!(#field_type), quote!(value)),
+            };
+
+            let mut lines = vec![format!(
+                "Replaces the `{}` field, which defaults to {}.",
+                field_name.unraw(),
+                default_doc.unwrap_or_default()
+            )];
+
+            if !docs.is_empty() {
+                lines.push(String::new());
+                lines.extend(docs);
+            }
+
+            let doc = build_doc_attributes(&lines);
+
+            quote! {
+                #doc
+                #[must_use]
+                #public #constant fn #name(mut self, value: #parameter) -> Self {
+                    self.#member = #value;
+                    self
+                }
+            }
+        })
+        .collect();
+
+    Ok(withers)
+}
+
+fn resolve_parameter_order(
+    fields: &[FieldData],
+    order: &[Ident],
+) -> syn::Result<Option<Vec<Ident>>> {
+    let parameters: Vec<_> = fields
+        .iter()
+        .filter(|field| matches!(field.default, DefaultValue::None))
+        .collect();
+
+    if !order.is_empty() {
+        let mut ordered = Vec::new();
+
+        if let Some(position) = fields.iter().find_map(|field| field.position.as_ref()) {
+            return Err(syn::Error::new_spanned(
+                position,
+                "'position' and 'order' cannot be combined.",
+            ));
+        }
+
+        for name in order {
+            let Some(field) = fields.iter().find(|field| field.is_called(name)) else {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!("Unknown field '{}' in 'order(...)'.", name),
+                ));
+            };
+
+            if ordered.contains(&field.name) {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!("Duplicate field '{}' in 'order(...)'.", name),
+                ));
+            }
+
+            if !matches!(field.default, DefaultValue::None) {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!(
+                        "'{}' has a default, so it is not a constructor parameter and can't be ordered.",
+                        name
+                    ),
+                ));
+            }
+
+            ordered.push(field.name.clone());
+        }
+
+        if let Some(missing) = parameters
+            .iter()
+            .find(|field| !ordered.contains(&field.name))
+        {
+            return Err(syn::Error::new_spanned(
+                &missing.name,
+                format!("'{}' is missing from 'order(...)'.", missing.name.unraw()),
+            ));
+        }
+
+        return Ok(Some(ordered));
+    }
+
+    if parameters.iter().all(|field| field.position.is_none()) {
+        return Ok(None);
+    }
+
+    let mut slots: Vec<Option<Ident>> = vec![None; parameters.len()];
+
+    for field in &parameters {
+        let Some(position) = &field.position else {
+            return Err(syn::Error::new_spanned(
+                &field.name,
+                format!(
+                    "'{}' needs a 'position' because other fields have one.",
+                    field.name.unraw()
+                ),
+            ));
+        };
+
+        let index: usize = position.base10_parse()?;
+
+        let Some(slot) = slots.get_mut(index) else {
+            return Err(syn::Error::new_spanned(
+                position,
+                format!(
+                    "Position {} leaves a gap. Positions must go from 0 to {}.",
+                    index,
+                    parameters.len() - 1
+                ),
+            ));
+        };
+
+        if slot.is_some() {
+            return Err(syn::Error::new_spanned(
+                position,
+                format!("Duplicate position {}.", index),
+            ));
+        }
+
+        *slot = Some(field.name.clone());
+    }
+
+    Ok(Some(slots.into_iter().flatten().collect()))
+}
+
+fn apply_constructor_defaults(
+    fields: &mut [FieldData],
+    constructor: &ConstructorProps,
+) -> syn::Result<()> {
+    for (name, default) in &constructor.defaults {
+        let Some(field) = fields.iter_mut().find(|field| field.is_called(name)) else {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("Unknown field '{}' in 'default(...)'.", name),
+            ));
+        };
+
+        field.default = default.clone();
+        field.default_doc = describe_default(default);
+        field.constructor_default = true;
+        field.into = false;
+        field.try_into = false;
+        field.into_iter = None;
+        field.some = None;
+        field.to_owned = None;
+        field.from_fn = false;
+    }
+
+    Ok(())
+}
+
+struct Failure {
+    error: TokenStream,
+    predicates: Vec<TokenStream>,
+    wrap_error: Option<Ident>,
+    items: TokenStream,
+}
+
+fn resolve_failure(
+    props: &MainProps,
+    has_validators: bool,
+    fields: &[FieldData],
+    new_name: &Ident,
+    error_name: Ident,
+    public: &TokenStream,
+) -> syn::Result<Option<Failure>> {
+    let conversions: Vec<_> = fields.iter().filter(|field| field.try_into).collect();
+
+    if let Some(error) = &props.error {
+        if !has_validators && conversions.is_empty() {
+            return Err(syn::Error::new_spanned(
+                error,
+                "'error' requires at least one validator or 'try_into' field.",
+            ));
+        }
+
+        let predicates = conversions
+            .iter()
+            .map(|field| {
+                let conversion_error = conversion_error_type(field);
+                quote!(#error: ::core::convert::From<#conversion_error>)
+            })
+            .collect();
+
+        return Ok(Some(Failure {
+            error: error.to_token_stream(),
+            predicates,
+            wrap_error: None,
+            items: quote!(),
+        }));
+    }
+
+    if has_validators {
+        return Err(syn::Error::new_spanned(
+            new_name,
+            "An error type is required for validators. Add #[new(error = MyError)] to the container.",
+        ));
+    }
+
+    match conversions.as_slice() {
+        [] => Ok(None),
+        [field] => Ok(Some(Failure {
+            error: conversion_error_type(field),
+            predicates: Vec::new(),
+            wrap_error: None,
+            items: quote!(),
+        })),
+        _ => {
+            let error_types = conversions.iter().map(|field| conversion_error_type(field));
+            let error = quote!(#error_name<#(#error_types),*>);
+            let items = generate_conversion_error(&error_name, &conversions, public);
+
+            Ok(Some(Failure {
+                error,
+                predicates: Vec::new(),
+                wrap_error: Some(error_name),
+                items,
+            }))
+        }
+    }
+}
+
+fn conversion_parameter(field: &FieldData) -> Ident {
+    format_ident!("__{}", to_pascal_case(&field.name))
+}
+
+fn conversion_variant(field: &FieldData) -> Ident {
+    format_ident!("{}", to_pascal_case(&field.name))
+}
+
+fn conversion_error_type(field: &FieldData) -> TokenStream {
+    let parameter = conversion_parameter(field);
+    let field_type = &field.field_type;
+
+    quote!(<#parameter as ::core::convert::TryInto<#field_type>>::Error)
+}
+
+fn build_conversion_parameters(fields: &[FieldData]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|field| field.try_into)
+        .map(|field| {
+            let parameter = conversion_parameter(field);
+            let field_type = &field.field_type;
+
+            quote!(#parameter: ::core::convert::TryInto<#field_type>)
+        })
+        .collect()
+}
+
+fn generate_conversion_error(
+    error_name: &Ident,
+    fields: &[&FieldData],
+    public: &TokenStream,
+) -> TokenStream {
+    let generics: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("E{}", index))
+        .collect();
+    let variants: Vec<_> = fields
+        .iter()
+        .map(|field| conversion_variant(field))
+        .collect();
+    let field_names: Vec<_> = fields
+        .iter()
+        .map(|field| field.name.unraw().to_string())
+        .collect();
+    let variant_docs = field_names
+        .iter()
+        .map(|name| format!("The `{}` argument could not be converted.", name));
+    let error_doc =
+        "Error returned when a `try_into` argument of a constructor could not be converted.";
+
+    let error_impl = if cfg!(feature = "std") {
+        quote! {
+            #[automatically_derived]
+            impl<#(#generics: ::std::error::Error + 'static),*> ::std::error::Error for #error_name<#(#generics),*> {
+                fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+                    match self {
+                        #(Self::#variants(error) => ::core::option::Option::Some(error),)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #public enum #error_name<#(#generics),*> {
+            #(
+                #[doc = #variant_docs]
+                #variants(#generics),
+            )*
+        }
+
+        #[automatically_derived]
+        impl<#(#generics: ::core::fmt::Display),*> ::core::fmt::Display for #error_name<#(#generics),*> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(Self::#variants(error) => ::core::write!(f, "failed to convert `{}`: {}", #field_names, error),)*
+                }
+            }
+        }
+
+        #error_impl
+    }
+}
+
+fn extract_fields(fields: &Fields) -> (Punctuated<Field, Comma>, bool) {
+    match fields {
+        Fields::Named(found) => (found.named.clone(), true),
+        Fields::Unnamed(found) => (found.unnamed.clone(), false),
+        Fields::Unit => Default::default(),
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (index, &current) in chars.iter().enumerate() {
+        if current.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                result.push('_');
+            }
+        }
+
+        result.extend(current.to_lowercase());
+    }
+
+    result
+}
+
+fn to_pascal_case(name: &Ident) -> String {
+    let name = name.unraw().to_string();
+    let pascal: String = name
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().into_iter().flat_map(char::to_uppercase);
+            first.chain(chars)
+        })
+        .collect();
+
+    if pascal.starts_with(|c: char| c.is_ascii_digit()) {
+        return format!("Field{}", pascal);
+    }
+
+    pascal
+}
+
+struct FieldData {
+    name: Ident,
+    member: Member,
+    field_type: Type,
+    default: DefaultValue,
+    into: bool,
+    try_into: bool,
+    into_iter: Option<Type>,
+    validate: Option<Path>,
+    with: bool,
+    some: Option<Type>,
+    position: Option<LitInt>,
+    docs: Vec<String>,
+    default_doc: Option<String>,
+    // Whether the default comes from the constructor's `default(...)`, which can see its `params(...)`.
+    constructor_default: bool,
+    from_fn: bool,
+    to_owned: Option<Type>,
+}
+
+impl FieldData {
+    fn is_called(&self, name: &Ident) -> bool {
+        self.name == *name || matches!(&self.member, Member::Named(member) if member == name)
+    }
+}
+
+fn collect_field_datas(
+    fields: &Punctuated<Field, Comma>,
+    container_with: bool,
+) -> syn::Result<Vec<FieldData>> {
+    let datas: Vec<FieldData> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| collect_field_data(index, field, container_with))
+        .collect::<syn::Result<_>>()?;
+
+    for (index, data) in datas.iter().enumerate() {
+        if datas[..index].iter().any(|other| other.name == data.name) {
+            return Err(syn::Error::new_spanned(
+                &data.name,
+                format!("Duplicate parameter name '{}'.", data.name.unraw()),
+            ));
+        }
+    }
+
+    Ok(datas)
+}
+
+fn collect_field_data(index: usize, field: &Field, container_with: bool) -> syn::Result<FieldData> {
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    };
+    let ty = field.ty.clone();
+    let FieldSettings {
+        default,
+        into,
+        try_into,
+        into_iter,
+        validate,
+        with,
+        optional,
+        some,
+        to_owned,
+        position,
+        name,
+        fill,
+    } = read_field_settings(field, container_with)?;
+
+    let ident = name
+        .or_else(|| field.ident.clone())
+        .unwrap_or_else(|| format_ident!("_{}", index));
+
+    // Described before the defaults are expanded, so the docs show what was written.
+    let default_doc = match &fill {
+        Some(("from_fn", function)) => Some(format!(
+            "`core::array::from_fn({})`",
+            render_tokens(&function.to_token_stream())
+        )),
+        _ if optional => Some("`None`".to_owned()),
+        _ => describe_default(&default),
+    };
+
+    let some = if optional || some {
+        option_inner(&ty)
+    } else {
+        None
+    };
+    let to_owned = if to_owned { borrowed_form(&ty) } else { None };
+    let with = with
+        || optional
+        || (container_with
+            && matches!(
+                default,
+                DefaultValue::Trait | DefaultValue::CustomFunction(_) | DefaultValue::Repeat { .. }
+            ));
+
+    let into_iter = match into_iter {
+        Some(Some(item)) => Some(item),
+        Some(None) => {
+            let item = infer_iterator_item(&ty).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &ty,
+                    "Cannot infer the item type for 'into_iter'. Specify it with #[new(into_iter = ItemType)].",
+                )
+            })?;
+
+            Some(item)
+        }
+        None => None,
+    };
+
+    Ok(FieldData {
+        name: ident,
+        member,
+        field_type: ty,
+        default,
+        into,
+        try_into,
+        into_iter,
+        validate,
+        with,
+        from_fn: matches!(fill, Some(("from_fn", _))),
+        some,
+        to_owned,
+        position,
+        docs: read_docs(&field.attrs),
+        default_doc,
+        constructor_default: false,
+    })
+}
+
+fn build_default_initializer(field: &FieldData, constant: bool) -> Option<TokenStream> {
+    use DefaultValue::{Computed, CustomFunction, PhantomData, Repeat, Trait, Unit};
+
+    match &field.default {
+        DefaultValue::None => None,
+        Unit => Some(quote!(())),
+        PhantomData => Some(quote!(::core::marker::PhantomData)),
+        Trait => Some(quote!(Default::default())),
+        CustomFunction(function) => Some(quote!(#function)),
+        Computed(expression) => Some(quote!(#expression)),
+        // `[element; N]` would require the element to be `Copy`.
+        Repeat { element, len } => Some(if constant {
+            quote!([const { #element }; #len])
+        } else {
+            quote!(::core::array::from_fn::<_, { #len }, _>(|_| #element))
+        }),
+    }
+}
+
+fn build_field_checks(fields: &[ConstructorField], names: &[Ident]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .zip(names)
+        .filter_map(|(field, name)| {
+            let validate = field.validate.as_ref()?;
+
+            Some(quote!(#validate(&#name)?;))
+        })
+        .collect()
+}
+
+// A field as it flows through constructor generation.
+struct ConstructorField {
+    name: Ident,
+    hidden: Ident,
+    member: Member,
+    field_type: Type,
+    // The parameter type, or `None` when the field has a default.
+    parameter: Option<Type>,
+    // The expression the field is initialized with.
+    value: TokenStream,
+    computed: bool,
+    constructor_default: bool,
+    validate: Option<Path>,
+    docs: Vec<String>,
+    default_doc: Option<String>,
+}
+
+fn build_constructor_fields(
+    fields: Vec<FieldData>,
+    constant: bool,
+    wrap_error: Option<&Ident>,
+) -> Vec<ConstructorField> {
+    fields
+        .into_iter()
+        .map(|mut field| {
+            let default = build_default_initializer(&field, constant);
+            let name = field.name.clone();
+            let hidden = hide_parameter(&name);
+            let member = field.member.clone();
+            let field_type = field.field_type.clone();
+            let computed = matches!(field.default, DefaultValue::Computed(_));
+            let constructor_default = field.constructor_default;
+            let validate = field.validate.clone();
+            let docs = field.docs.clone();
+            let default_doc = field.default_doc.clone();
+
+            field.name = hidden.clone();
+            let (parameter, value) = build_constructor_argument(field, default, wrap_error);
+
+            ConstructorField {
+                name,
+                hidden,
+                member,
+                field_type,
+                parameter,
+                value,
+                computed,
+                constructor_default,
+                validate,
+                docs,
+                default_doc,
+            }
+        })
+        .collect()
+}
+
+fn build_constructor_argument(
+    field: FieldData,
+    default: Option<TokenStream>,
+    wrap_error: Option<&Ident>,
+) -> (Option<Type>, TokenStream) {
+    if field.try_into {
+        let name = &field.name;
+        let parameter = conversion_parameter(&field);
+        let map_error = wrap_error.map(|error_name| {
+            let variant = conversion_variant(&field);
+            quote!(.map_err(#error_name::#variant))
+        });
+
+        let pass_value = quote!(::core::convert::TryInto::try_into(#name)#map_error?);
+
+        return (Some(parse_quote!(#parameter)), pass_value);
+    }
+
+    let FieldData {
+        name,
+        field_type,
+        into,
+        into_iter,
+        some,
+        to_owned,
+        ..
+    } = field;
+
+    if let (None, Some(inner)) = (&default, some) {
+        let pass_value = quote!(::core::option::Option::Some(#name));
+
+        return (Some(inner), pass_value);
+    }
+
+    if let (None, Some(borrowed)) = (&default, to_owned) {
+        let pass_value = build_owned_value(&field_type, &name);
+
+        return (Some(borrowed), pass_value);
+    }
+
+    if let (None, Some(item)) = (&default, into_iter) {
+        let parameter = parse_quote!(impl ::core::iter::IntoIterator<Item = #item>);
+        let pass_value = quote!(::core::iter::Iterator::collect(
+            ::core::iter::IntoIterator::into_iter(#name)
+        ));
+
+        return (Some(parameter), pass_value);
+    }
+
+    match default {
+        Some(token) => (None, token),
+        None => {
+            if into {
+                let parameter = parse_quote!(impl ::core::convert::Into<#field_type>);
+                let pass_value = quote!(#name.into());
+
+                (Some(parameter), pass_value)
+            } else {
+                let pass_value = quote!(#name);
+
+                (Some(field_type), pass_value)
+            }
+        }
+    }
+}
+
+fn build_instance(
+    target: &TokenStream,
+    is_named: bool,
+    members: &[Member],
+    pass_value: &[TokenStream],
+) -> TokenStream {
+    if is_named {
+        return quote! {
+            #target {
+                #(#members: #pass_value),*
+            }
+        };
+    }
+
+    if pass_value.is_empty() {
+        return quote!(#target);
+    }
+
+    quote!(#target(#(#pass_value),* ))
+}
+
+fn build_bound_instance(
+    target: &TokenStream,
+    is_named: bool,
+    members: &[Member],
+    names: &[Ident],
+    bindings: TokenStream,
+) -> TokenStream {
+    let bound_values: Vec<_> = names.iter().map(|name| quote!(#name)).collect();
+    let instance = build_instance(target, is_named, members, &bound_values);
+
+    quote! {
+        #bindings
+        #instance
+    }
+}
+
+// Parameters and defaults are bound under their hidden names first. The constructor's
+// `params(...)` are then exposed under their own names, and only the constructor's
+// `default(...)` expressions are evaluated after that, so field-level defaults can't see them.
+// When there are computed fields, the bindings are exposed under the field names and the
+// computed fields are evaluated in order, so their expressions can borrow the converted
+// parameters before the struct literal moves them.
+fn build_bindings(fields: &[ConstructorField], params: &[(Ident, Type)]) -> TokenStream {
+    let (computed, plain): (Vec<_>, Vec<_>) = fields.iter().partition(|field| field.computed);
+    let (scoped, unscoped): (Vec<_>, Vec<_>) =
+        plain.iter().partition(|field| field.constructor_default);
+
+    let bind = |field: &&ConstructorField| {
+        let ConstructorField {
+            hidden,
+            field_type,
+            value,
+            ..
+        } = field;
+        quote!(let #hidden: #field_type = #value;)
+    };
+    let bindings = unscoped.iter().map(bind);
+    let params = params.iter().map(|(name, _)| {
+        let hidden = hide_parameter(name);
+        quote!(let #name = #hidden;)
+    });
+    let scoped = scoped.iter().map(bind);
+    let bindings = quote! {
+        #(#bindings)*
+        #(#params)*
+        #(#scoped)*
+    };
+
+    if computed.is_empty() {
+        return bindings;
+    }
+
+    let exposed = plain.iter().map(|field| {
+        let ConstructorField { name, hidden, .. } = field;
+        quote!(let #name = #hidden;)
+    });
+    let computed = computed.iter().map(|field| {
+        let ConstructorField {
+            name,
+            field_type,
+            value,
+            ..
+        } = field;
+        quote!(let #name: #field_type = #value;)
+    });
+
+    quote! {
+        #bindings
+        #(#exposed)*
+        #(#computed)*
+    }
+}
+
+// Parameters use mixed-site hygiene, so default expressions pasted into the constructor
+// body can't see them and keep resolving to items from the surrounding scope.
+fn hide_parameter(name: &Ident) -> Ident {
+    let mut hidden = name.clone();
+    hidden.set_span(Span::mixed_site().located_at(name.span()));
+    hidden
+}
+
+fn build_fallible_instance(
+    target: &TokenStream,
+    is_named: bool,
+    members: &[Member],
+    names: &[Ident],
+    bindings: TokenStream,
+    container: Option<&Path>,
+    returns: Option<&ReturnWrapper>,
+) -> TokenStream {
+    let bound_values: Vec<_> = names.iter().map(|name| quote!(#name)).collect();
+    let instance = build_instance(target, is_named, members, &bound_values);
+    let container_check = container.map(|validate| quote!(#validate(&value)?;));
+    let value = returns.map_or_else(|| quote!(value), |returns| returns.wrap(quote!(value)));
+
+    quote! {
+        #bindings
+        let value = #instance;
+        #container_check
+        ::core::result::Result::Ok(#value)
+    }
+}
+
+fn build_constructor_doc(
+    summary: &str,
+    arguments: &[(Ident, Vec<String>)],
+    defaults: &[(Ident, String)],
+    errors: Option<&str>,
+    constant: bool,
+) -> Vec<String> {
+    let mut lines: Vec<String> = summary.lines().map(str::to_owned).collect();
+
+    if !arguments.is_empty() {
+        lines.extend(["".into(), "# Arguments".into(), "".into()]);
+
+        for (name, docs) in arguments {
+            let mut docs = docs.iter();
+
+            match docs.next() {
+                Some(first) => lines.push(format!("* `{}` - {}", name.unraw(), first)),
+                None => lines.push(format!("* `{}`", name.unraw())),
+            }
+
+            lines.extend(docs.map(|line| match line.is_empty() {
+                true => String::new(),
+                false => format!("  {}", line),
+            }));
+        }
+    }
+
+    if !defaults.is_empty() {
+        lines.extend(["".into(), "# Defaults".into(), "".into()]);
+        lines.extend(
+            defaults
+                .iter()
+                .map(|(name, default)| format!("* `{}` - {}", name.unraw(), default)),
+        );
+    }
+
+    if let Some(errors) = errors {
+        lines.extend(["".into(), "# Errors".into(), "".into(), errors.into()]);
+    }
+
+    if constant {
+        lines.extend(["".into(), "This constructor is `const`.".into()]);
+    }
+
+    lines
+}
+
+fn build_doc_attributes(lines: &[String]) -> TokenStream {
+    let lines = lines.iter().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", line)
+        }
+    });
+
+    quote!(#(#[doc = #lines])*)
+}
+
+fn describe_default(default: &DefaultValue) -> Option<String> {
+    let description = match default {
+        DefaultValue::None => return None,
+        DefaultValue::Unit => "`()`".to_owned(),
+        DefaultValue::PhantomData => "`PhantomData`".to_owned(),
+        DefaultValue::Trait => "`Default::default()`".to_owned(),
+        DefaultValue::CustomFunction(expression) => format!("`{}`", render_tokens(expression)),
+        DefaultValue::Computed(expression) => {
+            format!("computed as `{}`", render_tokens(expression))
+        }
+        DefaultValue::Repeat { element, .. } => {
+            format!("`{}` for every element", render_tokens(element))
+        }
+    };
+
+    Some(description)
+}
+
+// Renders an expression for the generated docs roughly the way rustfmt would print it.
+// Literals are copied verbatim, so the contents of strings are never touched.
+fn render_tokens(tokens: &TokenStream) -> String {
+    let mut rendered = String::new();
+    // Whether the next token is written without a space before it.
+    let mut glue = true;
+    // Whether the next token starts an operand, which makes `-`, `*`, `&`, `!` and `|` prefixes.
+    let mut expects_operand = true;
+    let mut generic_depth = 0;
+    let mut closure_parameters = false;
+
+    for atom in split_atoms(tokens.clone()) {
+        match atom {
+            RenderAtom::Word(word) => {
+                if !glue {
+                    rendered.push(' ');
+                }
+                rendered.push_str(&word);
+                glue = false;
+                expects_operand = false;
+            }
+            RenderAtom::Group(delimiter, stream) => {
+                let inner = render_tokens(&stream);
+                let attached = !expects_operand && delimiter != Delimiter::Brace;
+                if !glue && !attached {
+                    rendered.push(' ');
+                }
+                match delimiter {
+                    Delimiter::Parenthesis => rendered.push_str(&format!("({})", inner)),
+                    Delimiter::Bracket => rendered.push_str(&format!("[{}]", inner)),
+                    Delimiter::Brace if inner.is_empty() => rendered.push_str("{}"),
+                    Delimiter::Brace => rendered.push_str(&format!("{{ {} }}", inner)),
+                    Delimiter::None => rendered.push_str(&inner),
+                }
+                glue = false;
+                expects_operand = false;
+            }
+            RenderAtom::Op(op) => {
+                // Only turbofish, qualified paths and nested type arguments, so `K < 4` stays a
+                // comparison.
+                let starts_generics = op == "<" && (expects_operand || generic_depth > 0);
+                let closes_generics = op.chars().all(|c| c == '>') && generic_depth >= op.len();
+
+                let (space_before, space_after) = match op.as_str() {
+                    "::" | "." | ".." | "..=" => (false, false),
+                    "," | ";" | ":" => (false, true),
+                    "'" => (true, false),
+                    "!" if !expects_operand => (false, false),
+                    "-" | "*" | "&" | "!" if expects_operand => (true, false),
+                    "|" if closure_parameters => (false, true),
+                    "|" if expects_operand => (true, false),
+                    _ if starts_generics => (false, false),
+                    _ if closes_generics => (false, true),
+                    _ => (true, true),
+                };
+
+                if space_before && !glue {
+                    rendered.push(' ');
+                }
+                rendered.push_str(&op);
+
+                if starts_generics {
+                    generic_depth += 1;
+                } else if closes_generics {
+                    generic_depth -= op.len();
+                }
+
+                if op == "|" {
+                    closure_parameters = !closure_parameters && expects_operand;
+                }
+                expects_operand = !closes_generics;
+                glue = !space_after;
+            }
+        }
+    }
+
+    rendered
+}
+
+enum RenderAtom {
+    Word(String),
+    Op(String),
+    Group(Delimiter, TokenStream),
+}
+
+const COMPOUND_OPERATORS: &[&str] = &[
+    "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=", "^=", "&=",
+    "|=", "<<", ">>", "<<=", ">>=", "..", "..=", "...",
+];
+
+// Splits tokens into words, operators with their joint characters merged, and groups.
+fn split_atoms(tokens: TokenStream) -> Vec<RenderAtom> {
+    let mut atoms = Vec::new();
+    let mut joined = false;
+
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => atoms.push(RenderAtom::Word(ident.to_string())),
+            TokenTree::Literal(literal) => atoms.push(RenderAtom::Word(literal.to_string())),
+            TokenTree::Group(group) => {
+                atoms.push(RenderAtom::Group(group.delimiter(), group.stream()))
+            }
+            TokenTree::Punct(punct) => {
+                let character = punct.as_char();
+                match atoms.last_mut() {
+                    Some(RenderAtom::Op(op))
+                        if joined
+                            && COMPOUND_OPERATORS
+                                .contains(&format!("{}{}", op, character).as_str()) =>
+                    {
+                        op.push(character)
+                    }
+                    _ => atoms.push(RenderAtom::Op(character.to_string())),
+                }
+
+                joined = punct.spacing() == Spacing::Joint;
+                continue;
+            }
+        }
+
+        joined = false;
+    }
+
+    atoms
+}
+
+fn read_docs(attributes: &[Attribute]) -> Vec<String> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("doc"))
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(doc), ..
+                    }),
+                ..
+            }) => Some(doc.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+struct Signature {
+    error: TokenStream,
+    generic_params: Vec<TokenStream>,
+    predicates: Vec<TokenStream>,
+}
+
+fn generate_constructor(
+    instance: TokenStream,
+    constructor_field: &[(Ident, Type)],
+    new_name: Ident,
+    attributes: TokenStream,
+    qualifiers: TokenStream,
+    output: TokenStream,
+    signature: Option<Signature>,
+) -> TokenStream {
+    let constructor_field = constructor_field
+        .iter()
+        .map(|(name, parameter_type)| quote!(#name: #parameter_type));
+    let Some(Signature {
+        error,
+        generic_params,
+        predicates,
+    }) = signature
+    else {
+        return quote! {
+            #attributes
+            #qualifiers fn #new_name(#(#constructor_field),*) -> #output {
+                #instance
+            }
+        };
+    };
+
+    let generics = (!generic_params.is_empty()).then(|| quote!(<#(#generic_params),*>));
+    let where_clause = (!predicates.is_empty()).then(|| quote!(where #(#predicates),*));
+
+    quote! {
+        #attributes
+        #qualifiers fn #new_name #generics(#(#constructor_field),*) -> ::core::result::Result<#output, #error>
+        #where_clause
+        {
+            #instance
+        }
+    }
+}
+
+fn generate_wrapped_constructor(
+    wrapped: &ReturnWrapper,
+    constructor_field: &[(Ident, Type)],
+    constructor: &ConstructorProps,
+    signature: Option<&Signature>,
+) -> TokenStream {
+    let new_name = &constructor.name;
+    let wrapped_name = format_ident!("{}_{}", new_name.unraw(), wrapped.suffix());
+    let names = constructor_field.iter().map(|(name, _)| name);
+    let call = quote!(Self::#new_name(#(#names),*));
+
+    let summary = format!(
+        "Same as [`Self::{}`], but returns the value in {}.",
+        new_name.unraw(),
+        wrapped.describe()
+    );
+    let forwarded = constructor
+        .attributes
+        .iter()
+        .filter(|attribute| !attribute.path().is_ident("must_use"));
+    let must_use = (constructor.must_use && signature.is_none()).then(|| quote!(#[must_use]));
+    let attributes = quote!(#[doc = #summary] #(#[#forwarded])* #must_use);
+
+    let instance = match signature {
+        Some(_) => {
+            let value = wrapped.wrap(quote!(value));
+            quote!(#call.map(|value| #value))
+        }
+        None => wrapped.wrap(call),
+    };
+    let public = &constructor.visibility;
+
+    generate_constructor(
+        instance,
+        constructor_field,
+        wrapped_name,
+        attributes,
+        quote!(#public),
+        wrapped.output(),
+        signature.cloned(),
+    )
+}
+
+#[derive(Debug, Clone)]
+enum DefaultValue {
+    None,
+    Unit,
+    PhantomData,
+    Trait,
+    CustomFunction(TokenStream),
+    Computed(TokenStream),
+    Repeat {
+        element: TokenStream,
+        len: TokenStream,
+    },
+}
+
+struct FieldSettings {
+    default: DefaultValue,
+    into: bool,
+    try_into: bool,
+    into_iter: Option<Option<Type>>,
+    validate: Option<Path>,
+    with: bool,
+    optional: bool,
+    some: bool,
+    to_owned: bool,
+    position: Option<LitInt>,
+    name: Option<Ident>,
+    fill: Option<(&'static str, Expr)>,
+}
+
+fn read_field_settings(field: &Field, container_with: bool) -> syn::Result<FieldSettings> {
+    let mut settings = FieldSettings {
+        default: DefaultValue::None,
+        into: false,
+        try_into: false,
+        into_iter: None,
+        validate: None,
+        with: false,
+        optional: false,
+        some: false,
+        to_owned: false,
+        position: None,
+        name: None,
+        fill: None,
+    };
+
+    let mut seen_new_attribute = false;
+
+    for attribute in &field.attrs {
+        if !attribute.path().is_ident("new") {
+            continue;
+        }
+
+        if seen_new_attribute {
+            return Err(syn::Error::new_spanned(
+                attribute,
+                "Multiple #[new(...)] attributes found. Ensure the field has only one #[new(...)] attribute.",
+            ));
+        }
+
+        seen_new_attribute = true;
+
+        let mut has_arguments = false;
+
+        attribute.parse_nested_meta(|meta| {
+            has_arguments = true;
+            field_settings_parser(meta, &mut settings)
+        })?;
+
+        if !has_arguments {
+            return Err(syn::Error::new_spanned(
+                attribute,
+                "Expected an argument in #[new(...)] attribute.",
+            ));
+        }
+    }
+
+    if let Some((key, expression)) = &settings.fill {
+        if !matches!(settings.default, DefaultValue::None) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "'{}' cannot be combined with 'default' or 'value' in the same #[new(...)] attribute.",
+                    key
+                ),
+            ));
+        }
+
+        let Type::Array(array) = &field.ty else {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "'{}' requires the field type to be an array like [T; N].",
+                    key
+                ),
+            ));
+        };
+
+        let len = &array.len;
+        settings.default = match *key {
+            "repeat" => DefaultValue::Repeat {
+                element: expression.to_token_stream(),
+                len: len.to_token_stream(),
+            },
+            _ => DefaultValue::CustomFunction(
+                quote!(::core::array::from_fn::<_, { #len }, _>(#expression)),
+            ),
+        };
+    }
+
+    let conversions = [
+        ("into", settings.into),
+        ("try_into", settings.try_into),
+        ("into_iter", settings.into_iter.is_some()),
+        ("optional", settings.optional),
+        ("some", settings.some),
+        ("to_owned", settings.to_owned),
+    ];
+    let mut enabled = conversions
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(key, _)| *key);
+    let conversion = enabled.next();
+
+    if let (Some(first), Some(second)) = (conversion, enabled.next()) {
+        return Err(syn::Error::new_spanned(
+            field,
+            format!(
+                "'{}' and '{}' cannot be combined in the same #[new(...)] attribute.",
+                first, second
+            ),
+        ));
+    }
+
+    let has_wither = settings.with || container_with;
+
+    if let Some(conversion) = conversion {
+        let used_by_wither = has_wither && matches!(conversion, "into" | "into_iter");
+
+        if let Some((key, _)) = &settings.fill {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "'{}' and '{}' cannot be combined in the same #[new(...)] attribute.",
+                    conversion, key
+                ),
+            ));
+        }
+
+        if matches!(settings.default, DefaultValue::Computed(_)) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "'{}' and 'value' cannot be combined in the same #[new(...)] attribute.",
+                    conversion
+                ),
+            ));
+        }
+
+        if !matches!(settings.default, DefaultValue::None) && !used_by_wither {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "'{}' and 'default' cannot be combined in the same #[new(...)] attribute.",
+                    conversion
+                ),
+            ));
+        }
+    }
+
+    if settings.to_owned && borrowed_form(&field.ty).is_none() {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "'to_owned' requires the field type to be String, Vec<T>, PathBuf, OsString, CString, Box<str> or Box<[T]>.",
+        ));
+    }
+
+    if let Some(conversion @ ("optional" | "some")) = conversion {
+        if option_inner(&field.ty).is_none() {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!("'{}' requires the field type to be Option<T>.", conversion),
+            ));
+        }
+    }
+
+    if settings.optional {
+        settings.default = DefaultValue::CustomFunction(quote!(::core::option::Option::None));
+    }
+
+    if settings.with
+        && !matches!(
+            settings.default,
+            DefaultValue::Trait | DefaultValue::CustomFunction(_) | DefaultValue::Repeat { .. }
+        )
+    {
+        return Err(syn::Error::new_spanned(
+            field,
+            "'with' requires a 'default' key in the same #[new(...)] attribute.",
+        ));
+    }
+
+    if !settings.try_into && settings.into_iter.is_none() {
+        detect_automatic_defaults(&mut settings.default, field);
+    }
+
+    if let Some(position) = &settings.position {
+        if !matches!(settings.default, DefaultValue::None) {
+            return Err(syn::Error::new_spanned(
+                position,
+                "'position' can't be used on a field with a default, since it is not a constructor parameter.",
+            ));
+        }
+    }
+
+    Ok(settings)
+}
+
+fn detect_automatic_defaults(default_value: &mut DefaultValue, field: &Field) {
+    if !matches!(&default_value, DefaultValue::None) {
+        return;
+    }
+
+    if is_phantom_data(&field.ty) {
+        *default_value = DefaultValue::PhantomData;
+        return;
+    }
+
+    let Type::Tuple(TypeTuple { elems, .. }) = &field.ty else {
+        return;
+    };
+
+    if elems.is_empty() {
+        *default_value = DefaultValue::Unit;
+    }
+}
+
+fn parse_name(lit: &LitStr, kind: &str) -> syn::Result<Ident> {
+    let mut name: Ident = syn::parse_str(&lit.value()).map_err(|_| {
+        syn::Error::new(
+            lit.span(),
+            format!("'{}' is not a valid {} name.", lit.value(), kind),
+        )
+    })?;
+    name.set_span(lit.span());
+    Ok(name)
+}
+
+fn field_settings_parser(
+    meta: ParseNestedMeta<'_>,
+    settings: &mut FieldSettings,
+) -> syn::Result<()> {
+    if meta.path.is_ident("into") {
+        if settings.into {
+            return Err(meta.error("Duplicate 'into' key found in #[new(...)] attribute."));
+        }
+
+        settings.into = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("try_into") {
+        if settings.try_into {
+            return Err(meta.error("Duplicate 'try_into' key found in #[new(...)] attribute."));
+        }
+
+        settings.try_into = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("into_iter") {
+        if settings.into_iter.is_some() {
+            return Err(meta.error("Duplicate 'into_iter' key found in #[new(...)] attribute."));
+        }
+
+        if !meta.input.peek(Token![=]) {
+            settings.into_iter = Some(None);
+            return Ok(());
+        }
+
+        meta.input.parse::<Token![=]>()?;
+        settings.into_iter = Some(Some(meta.input.parse()?));
+        return Ok(());
+    }
+
+    if meta.path.is_ident("with") {
+        if settings.with {
+            return Err(meta.error("Duplicate 'with' key found in #[new(...)] attribute."));
+        }
+
+        settings.with = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("optional") {
+        if settings.optional {
+            return Err(meta.error("Duplicate 'optional' key found in #[new(...)] attribute."));
+        }
+
+        settings.optional = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("to_owned") {
+        if settings.to_owned {
+            return Err(meta.error("Duplicate 'to_owned' key found in #[new(...)] attribute."));
+        }
+
+        settings.to_owned = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("some") {
+        if settings.some {
+            return Err(meta.error("Duplicate 'some' key found in #[new(...)] attribute."));
+        }
+
+        settings.some = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("name") {
+        if settings.name.is_some() {
+            return Err(meta.error("Duplicate 'name' key found in #[new(...)] attribute."));
+        }
+
+        let lit: LitStr = meta.value()?.parse()?;
+        settings.name = Some(parse_name(&lit, "parameter")?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("position") {
+        if settings.position.is_some() {
+            return Err(meta.error("Duplicate 'position' key found in #[new(...)] attribute."));
+        }
+
+        let lit: LitInt = meta.value()?.parse()?;
+        lit.base10_parse::<usize>()?;
+        settings.position = Some(lit);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("validate") {
+        if settings.validate.is_some() {
+            return Err(meta.error("Duplicate 'validate' key found in #[new(...)] attribute."));
+        }
+
+        let value = meta.value()?;
+        settings.validate = Some(value.parse()?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("repeat") || meta.path.is_ident("from_fn") {
+        let key = if meta.path.is_ident("repeat") {
+            "repeat"
+        } else {
+            "from_fn"
+        };
+
+        if let Some((existing, _)) = &settings.fill {
+            return Err(meta.error(match *existing == key {
+                true => format!("Duplicate '{}' key found in #[new(...)] attribute.", key),
+                false => {
+                    "'repeat' and 'from_fn' cannot be combined in the same #[new(...)] attribute."
+                        .to_owned()
+                }
+            }));
+        }
+
+        let expression: Expr = meta.value()?.parse()?;
+        settings.fill = Some((key, expression));
+        return Ok(());
+    }
+
+    if meta.path.is_ident("value") {
+        let default_value = &mut settings.default;
+
+        if matches!(default_value, DefaultValue::Computed(_)) {
+            return Err(meta.error("Duplicate 'value' key found in #[new(...)] attribute."));
+        }
+
+        if !matches!(default_value, DefaultValue::None) {
+            return Err(meta.error(
+                "'value' and 'default' cannot be combined in the same #[new(...)] attribute.",
+            ));
+        }
+
+        let expression: Expr = meta.value()?.parse()?;
+        *default_value = DefaultValue::Computed(expression.into_token_stream());
+        return Ok(());
+    }
+
+    if meta.path.is_ident("default") {
+        let default_value = &mut settings.default;
+
+        if matches!(default_value, DefaultValue::Computed(_)) {
+            return Err(meta.error(
+                "'value' and 'default' cannot be combined in the same #[new(...)] attribute.",
+            ));
+        }
+
+        if !matches!(default_value, DefaultValue::None) {
+            return Err(meta.error("Duplicate 'default' key found in #[new(...)] attribute."));
+        }
+
+        if !meta.input.peek(Token![=]) {
+            *default_value = DefaultValue::Trait;
+            return Ok(());
+        }
+
+        meta.input.parse::<Token![=]>()?;
+        let default_expression: Expr = meta.input.parse()?;
+        *default_value = DefaultValue::CustomFunction(default_expression.into_token_stream());
+        return Ok(());
+    }
+
+    Err(meta.error("Unknown argument found in #[new(...)] attribute."))
+}
+
+fn borrowed_form(ty: &Type) -> Option<Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let last = path.segments.last()?;
+
+    let argument = match &last.arguments {
+        PathArguments::None => None,
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first()? {
+                GenericArgument::Type(argument) => Some(argument),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let borrowed = match (last.ident.to_string().as_str(), argument) {
+        ("String", None) => parse_quote!(&str),
+        ("PathBuf", None) => parse_quote!(&::std::path::Path),
+        ("OsString", None) => parse_quote!(&::std::ffi::OsStr),
+        ("CString", None) => parse_quote!(&::core::ffi::CStr),
+        ("Vec", Some(item)) => parse_quote!(&[#item]),
+        ("Box", Some(Type::Slice(slice))) => parse_quote!(&#slice),
+        ("Box", Some(Type::Path(inner))) if inner.path.is_ident("str") => parse_quote!(&str),
+        _ => return None,
+    };
+
+    Some(borrowed)
+}
+
+fn build_owned_value(ty: &Type, name: &Ident) -> TokenStream {
+    let is_boxed = matches!(
+        ty,
+        Type::Path(TypePath { path, .. })
+            if path.segments.last().is_some_and(|last| last.ident == "Box")
+    );
+
+    if is_boxed {
+        return quote!(::core::convert::Into::into(#name));
+    }
+
+    let alloc = alloc_root();
+    quote!(#alloc::borrow::ToOwned::to_owned(#name))
+}
+
+fn is_phantom_data(ty: &Type) -> bool {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return false;
+    };
+
+    let Some(last) = path.segments.last() else {
+        return false;
+    };
+
+    last.ident == "PhantomData"
+}
+
+fn const_default(ty: &Type) -> Option<TokenStream> {
+    match ty {
+        Type::Paren(paren) => const_default(&paren.elem),
+        Type::Group(group) => const_default(&group.elem),
+        Type::Array(array) => {
+            let element = const_default(&array.elem)?;
+            let len = &array.len;
+            Some(quote!([const { #element }; #len]))
+        }
+        Type::Tuple(TypeTuple { elems, .. }) => {
+            let elements = elems
+                .iter()
+                .map(const_default)
+                .collect::<Option<Vec<_>>>()?;
+            Some(quote!((#(#elements,)*)))
+        }
+        Type::Reference(reference) if reference.mutability.is_none() => {
+            match reference.elem.as_ref() {
+                Type::Slice(_) => Some(quote!(&[])),
+                Type::Path(TypePath { qself: None, path }) if path.is_ident("str") => {
+                    Some(quote!(""))
+                }
+                _ => None,
+            }
+        }
+        Type::Path(TypePath { qself: None, path }) => {
+            // Other crates have their own types with these names, like `parking_lot::Mutex`.
+            let is_standard = path.segments.len() == 1 && path.leading_colon.is_none()
+                || path.segments.first().is_some_and(|first| {
+                    matches!(first.ident.to_string().as_str(), "std" | "core" | "alloc")
+                });
+            if !is_standard {
+                return None;
+            }
+
+            let last = path.segments.last()?;
+            let name = last.ident.to_string();
+
+            if matches!(last.arguments, PathArguments::None) {
+                let default = match name.as_str() {
+                    "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32"
+                    | "i64" | "i128" | "isize" => quote!(0),
+                    "f32" | "f64" => quote!(0.0),
+                    "bool" => quote!(false),
+                    "char" => quote!('\0'),
+                    "String" => quote!(<#ty>::new()),
+                    "Duration" => quote!(<#ty>::ZERO),
+                    "AtomicBool" => quote!(<#ty>::new(false)),
+                    _ if name.starts_with("Atomic") && name != "AtomicPtr" => {
+                        quote!(<#ty>::new(0))
+                    }
+                    _ => return None,
+                };
+
+                return Some(default);
+            }
+
+            if option_inner(ty).is_some() {
+                return Some(quote!(::core::option::Option::None));
+            }
+
+            match name.as_str() {
+                "Vec" | "VecDeque" | "LinkedList" | "BTreeMap" | "BTreeSet" | "BinaryHeap"
+                | "OnceCell" | "OnceLock" => Some(quote!(<#ty>::new())),
+                "Cell" | "RefCell" | "UnsafeCell" | "Mutex" | "RwLock" => {
+                    let PathArguments::AngleBracketed(arguments) = &last.arguments else {
+                        return None;
+                    };
+
+                    let Some(GenericArgument::Type(inner)) = arguments.args.first() else {
+                        return None;
+                    };
+
+                    let inner = const_default(inner)?;
+                    Some(quote!(<#ty>::new(#inner)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_trivially_dropped(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(_) | Type::Ptr(_) | Type::BareFn(_) | Type::Never(_) => true,
+        Type::Paren(paren) => is_trivially_dropped(&paren.elem),
+        Type::Group(group) => is_trivially_dropped(&group.elem),
+        Type::Array(array) => is_trivially_dropped(&array.elem),
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter().all(is_trivially_dropped),
+        Type::Path(TypePath { qself: None, path }) => {
+            let Some(last) = path.segments.last() else {
+                return false;
+            };
+
+            if last.ident == "PhantomData" {
+                return true;
+            }
+
+            if let Some(inner) = option_inner(ty) {
+                return is_trivially_dropped(&inner);
+            }
+
+            path.segments.len() == 1
+                && matches!(last.arguments, PathArguments::None)
+                && matches!(
+                    last.ident.to_string().as_str(),
+                    "bool"
+                        | "char"
+                        | "u8"
+                        | "u16"
+                        | "u32"
+                        | "u64"
+                        | "u128"
+                        | "usize"
+                        | "i8"
+                        | "i16"
+                        | "i32"
+                        | "i64"
+                        | "i128"
+                        | "isize"
+                        | "f32"
+                        | "f64"
+                )
+        }
+        _ => false,
+    }
+}
+
+fn option_inner(ty: &Type) -> Option<Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let last = path.segments.last()?;
+
+    if last.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &last.arguments else {
+        return None;
+    };
+
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some(inner.clone()),
+        _ => None,
+    }
+}
+
+fn infer_iterator_item(ty: &Type) -> Option<Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+
+    let last = path.segments.last()?;
+
+    let PathArguments::AngleBracketed(arguments) = &last.arguments else {
+        return None;
+    };
+
+    let mut types = arguments.args.iter().filter_map(|argument| match argument {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+
+    let first = types.next()?;
+
+    if last.ident.to_string().ends_with("Map") {
+        let second = types.next()?;
+        return Some(parse_quote!((#first, #second)));
+    }
+
+    if let Type::Slice(TypeSlice { elem, .. }) = first {
+        return Some(elem.as_ref().clone());
+    }
+
+    Some(first.clone())
+}
+
+struct MainProps {
+    pub visibility: Visibility,
+    pub rename: Option<Ident>,
+    pub constant: bool,
+    pub auto_constant: bool,
+    pub validate: Option<Path>,
+    pub error: Option<Type>,
+    pub builder: BuilderKind,
+    pub constructors: Vec<ConstructorProps>,
+    pub impl_default: bool,
+    pub with: bool,
+    pub args_struct: bool,
+    pub from: bool,
+    pub order: Vec<Ident>,
+    pub doc: Option<String>,
+    pub attributes: Vec<Meta>,
+    pub must_use: bool,
+    pub returns: Option<ReturnWrapper>,
+    pub wrapped: Option<ReturnWrapper>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BuilderKind {
+    Runtime,
+    Typestate,
+}
+
+#[derive(Default)]
+struct RawMainProps {
+    visibility: Option<RawVisibility>,
+    rename: Option<Ident>,
+    constant: Option<ConstMode>,
+    validate: Option<Path>,
+    error: Option<Type>,
+    builder: Option<BuilderKind>,
+    constructors: Vec<RawConstructorProps>,
+    impl_default: Option<bool>,
+    with: bool,
+    args_struct: bool,
+    from: bool,
+    order: Vec<Ident>,
+    doc: Option<String>,
+    attributes: Vec<Meta>,
+    must_use: Option<bool>,
+    returns: Option<ReturnWrapper>,
+    wrapped: Option<ReturnWrapper>,
+}
+
+impl MainProps {
+    fn from_attributes(attributes: &[Attribute], visibility: &Visibility) -> syn::Result<Self> {
+        let mut raw = RawMainProps::default();
+
+        let mut seen_new_attribute = false;
+
+        for attribute in attributes {
+            if !attribute.path().is_ident("new") {
+                continue;
+            }
+
+            if seen_new_attribute {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "Multiple #[new(...)] attributes found. Ensure only one is used on the struct.",
+                ));
+            }
+
+            seen_new_attribute = true;
+            let mut has_arguments = false;
+
+            attribute.parse_nested_meta(|meta| {
+                has_arguments = true;
+                main_props_parser(meta, &mut raw)
+            })?;
+
+            if !has_arguments {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "Expected at least one argument inside #[new(...)] attribute.",
+                ));
+            }
+        }
+
+        let resolve = |raw_visibility: RawVisibility| match raw_visibility {
+            RawVisibility::Explicit(explicit) => explicit,
+            RawVisibility::Inherit => visibility.clone(),
+        };
+        let public = raw
+            .visibility
+            .map(resolve)
+            .unwrap_or_else(|| parse_quote!(pub));
+        let constant = raw.constant.unwrap_or(ConstMode::Disabled);
+        let must_use = raw.must_use.unwrap_or(true);
+
+        let mut constructors: Vec<ConstructorProps> = Vec::new();
+
+        for constructor in raw.constructors {
+            let name = constructor.name.ok_or_else(|| {
+                syn::Error::new(
+                    constructor.span,
+                    "Expected a 'name' key in 'constructor(...)'.",
+                )
+            })?;
+
+            if constructors.iter().any(|existing| existing.name == name) {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "Duplicate constructor name found in #[new(...)] attribute.",
+                ));
+            }
+
+            let (returns, wrapped) =
+                if constructor.returns.is_some() || constructor.wrapped.is_some() {
+                    (constructor.returns, constructor.wrapped)
+                } else {
+                    (raw.returns.clone(), raw.wrapped.clone())
+                };
+
+            constructors.push(ConstructorProps {
+                name,
+                visibility: constructor
+                    .visibility
+                    .map(resolve)
+                    .unwrap_or_else(|| public.clone()),
+                constant: constructor.constant.unwrap_or(constant) == ConstMode::Enabled,
+                auto_constant: constructor.constant.unwrap_or(constant) == ConstMode::Auto,
+                defaults: constructor.defaults,
+                params: constructor.params,
+                args_struct: constructor.args_struct || raw.args_struct,
+                doc: constructor.doc.or_else(|| raw.doc.clone()),
+                attributes: raw
+                    .attributes
+                    .iter()
+                    .cloned()
+                    .chain(constructor.attributes)
+                    .collect(),
+                must_use: constructor.must_use.unwrap_or(must_use),
+                returns,
+                wrapped,
+            });
+        }
+
+        Ok(Self {
+            visibility: public,
+            rename: raw.rename,
+            constant: constant == ConstMode::Enabled,
+            auto_constant: constant == ConstMode::Auto,
+            validate: raw.validate,
+            error: raw.error,
+            builder: raw.builder.unwrap_or(BuilderKind::Runtime),
+            constructors,
+            impl_default: raw.impl_default.unwrap_or(false),
+            with: raw.with,
+            args_struct: raw.args_struct,
+            from: raw.from,
+            order: raw.order,
+            doc: raw.doc,
+            attributes: raw.attributes,
+            must_use,
+            returns: raw.returns,
+            wrapped: raw.wrapped,
+        })
+    }
+}
+
+fn main_props_parser(meta: ParseNestedMeta<'_>, raw: &mut RawMainProps) -> syn::Result<()> {
+    if meta.path.is_ident("pub") || meta.path.is_ident("vis") {
+        if raw.visibility.is_some() {
+            return Err(meta.error("Duplicate 'pub' or 'vis' key found in #[new(...)] attribute."));
+        }
+
+        raw.visibility = Some(parse_visibility(&meta)?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("rename") {
+        if raw.rename.is_some() {
+            return Err(meta.error("Duplicate 'rename' key found in #[new(...)] attribute."));
+        }
+
+        let value = meta.value()?;
+        let lit: LitStr = value.parse()?;
+        raw.rename = Some(parse_name(&lit, "constructor")?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("const") {
+        if raw.constant.is_some() {
+            return Err(meta.error("Duplicate 'const' key found in #[new(...)] attribute."));
+        }
+
+        raw.constant = Some(parse_const_mode(&meta)?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("validate") {
+        if raw.validate.is_some() {
+            return Err(meta.error("Duplicate 'validate' key found in #[new(...)] attribute."));
+        }
+
+        let value = meta.value()?;
+        raw.validate = Some(value.parse()?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("error") {
+        if raw.error.is_some() {
+            return Err(meta.error("Duplicate 'error' key found in #[new(...)] attribute."));
+        }
+
+        let value = meta.value()?;
+        raw.error = Some(value.parse()?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("with") {
+        if raw.with {
+            return Err(meta.error("Duplicate 'with' key found in #[new(...)] attribute."));
+        }
+
+        raw.with = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("args_struct") {
+        if raw.args_struct {
+            return Err(meta.error("Duplicate 'args_struct' key found in #[new(...)] attribute."));
+        }
+
+        raw.args_struct = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("doc") {
+        if raw.doc.is_some() {
+            return Err(meta.error("Duplicate 'doc' key found in #[new(...)] attribute."));
+        }
+
+        let lit: LitStr = meta.value()?.parse()?;
+        raw.doc = Some(lit.value());
+        return Ok(());
+    }
+
+    if meta.path.is_ident("attr") {
+        raw.attributes.push(parse_forwarded_attribute(&meta)?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("returns") || meta.path.is_ident("wrapped") {
+        return parse_return_wrapper(&meta, &mut raw.returns, &mut raw.wrapped);
+    }
+
+    if meta.path.is_ident("must_use") {
+        if raw.must_use.is_some() {
+            return Err(meta.error("Duplicate 'must_use' key found in #[new(...)] attribute."));
+        }
+
+        let lit: LitBool = meta.value()?.parse()?;
+        raw.must_use = Some(lit.value);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("order") {
+        if !raw.order.is_empty() {
+            return Err(meta.error("Duplicate 'order' key found in #[new(...)] attribute."));
+        }
+
+        return meta.parse_nested_meta(|meta| {
+            let Some(name) = meta.path.get_ident().cloned() else {
+                return Err(meta.error("Expected a field name in 'order(...)'."));
+            };
+
+            raw.order.push(name);
+            Ok(())
+        });
+    }
+
+    if meta.path.is_ident("from") {
+        if raw.from {
+            return Err(meta.error("Duplicate 'from' key found in #[new(...)] attribute."));
+        }
+
+        raw.from = true;
+        return Ok(());
+    }
+
+    if meta.path.is_ident("impl_default") {
+        if raw.impl_default.is_some() {
+            return Err(meta.error("Duplicate 'impl_default' key found in #[new(...)] attribute."));
+        }
+
+        let value = meta.value()?;
+        let lit: LitBool = value.parse()?;
+        raw.impl_default = Some(lit.value);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("constructor") {
+        let mut constructor = RawConstructorProps {
+            span: meta.path.span(),
+            ..Default::default()
+        };
+
+        meta.parse_nested_meta(|meta| constructor_props_parser(meta, &mut constructor))?;
+        raw.constructors.push(constructor);
+        return Ok(());
     }
-}
 
-struct FieldData {
-    name: Ident,
-    field_type: Type,
-    default: DefaultValue,
-    into: bool,
-}
+    if meta.path.is_ident("builder") {
+        if raw.builder.is_some() {
+            return Err(meta.error("Duplicate 'builder' key found in #[new(...)] attribute."));
+        }
 
-fn collect_field_datas(fields: &Punctuated<Field, Comma>) -> syn::Result<Vec<FieldData>> {
-    fields
-        .iter()
-        .enumerate()
-        .map(|(index, field)| collect_field_data(index, field))
-        .collect()
-}
+        let value = meta.value()?;
+        let kind: Ident = value.parse()?;
 
-fn collect_field_data(index: usize, field: &Field) -> syn::Result<FieldData> {
-    let ident = field
-        .ident
-        .clone()
-        .unwrap_or_else(|| format_ident!("_{}", index));
-    let ty = field.ty.clone();
-    let (default, into) = read_field_settings(field)?;
+        raw.builder = Some(match kind.to_string().as_str() {
+            "runtime" => BuilderKind::Runtime,
+            "typestate" => BuilderKind::Typestate,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    kind,
+                    "Expected 'runtime' or 'typestate' as the builder kind.",
+                ))
+            }
+        });
+        return Ok(());
+    }
 
-    Ok(FieldData {
-        name: ident,
-        field_type: ty,
-        default,
-        into,
-    })
+    Err(meta.error("Unknown argument in #[new(...)] attribute."))
 }
 
-fn build_default_initializers(
-    field_specs: &[FieldData],
-    is_named: bool,
-) -> Vec<Option<TokenStream>> {
-    field_specs
-        .iter()
-        .map(|field_data| build_default_initializer(field_data, is_named))
-        .collect()
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConstMode {
+    Disabled,
+    Enabled,
+    Auto,
 }
 
-fn build_default_initializer(field: &FieldData, is_named: bool) -> Option<TokenStream> {
-    let FieldData { name, default, .. } = field;
+fn parse_const_mode(meta: &ParseNestedMeta<'_>) -> syn::Result<ConstMode> {
+    let value = meta.value()?;
 
-    if is_named {
-        build_named_initializer(name, default)
-    } else {
-        build_unnamed_initializer(default)
+    if value.peek(LitBool) {
+        let lit: LitBool = value.parse()?;
+        return Ok(if lit.value {
+            ConstMode::Enabled
+        } else {
+            ConstMode::Disabled
+        });
     }
-}
 
-fn build_named_initializer(name: &Ident, default: &DefaultValue) -> Option<TokenStream> {
-    use DefaultValue::{CustomFunction, PhantomData, Trait, Unit};
+    let ident: Ident = value.parse()?;
 
-    match default {
-        DefaultValue::None => None,
-        Unit => Some(quote!(#name: ())),
-        PhantomData => Some(quote!(#name: ::core::marker::PhantomData)),
-        Trait => Some(quote!(#name: Default::default())),
-        CustomFunction(function) => Some(quote!(#name: #function)),
+    if ident != "auto" {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "Expected 'true', 'false' or 'auto' as the 'const' value.",
+        ));
     }
-}
 
-fn build_unnamed_initializer(default: &DefaultValue) -> Option<TokenStream> {
-    use DefaultValue::{CustomFunction, PhantomData, Trait, Unit};
+    Ok(ConstMode::Auto)
+}
 
-    match default {
-        DefaultValue::None => None,
-        Unit => Some(quote!(())),
-        PhantomData => Some(quote!(::core::marker::PhantomData)),
-        Trait => Some(quote!(Default::default())),
-        CustomFunction(function) => Some(quote!(#function)),
-    }
+enum RawVisibility {
+    Explicit(Visibility),
+    Inherit,
 }
 
-fn build_constructor_arguments(
-    fields: Vec<FieldData>,
-    defaults: Vec<Option<TokenStream>>,
-    is_named: bool,
-) -> (Vec<TokenStream>, Vec<TokenStream>) {
-    let (parameter, pass_value): (Vec<_>, Vec<_>) = fields
-        .into_iter()
-        .zip(defaults)
-        .map(|(field, default)| build_constructor_argument(field, default, is_named))
-        .unzip();
+fn parse_visibility(meta: &ParseNestedMeta<'_>) -> syn::Result<RawVisibility> {
+    let value = meta.value()?;
 
-    let constructor_field = parameter.into_iter().flatten().collect();
+    if meta.path.is_ident("pub") {
+        let lit: LitBool = value.parse()?;
+        let visibility = if lit.value {
+            parse_quote!(pub)
+        } else {
+            Visibility::Inherited
+        };
 
-    (constructor_field, pass_value)
-}
+        return Ok(RawVisibility::Explicit(visibility));
+    }
 
-fn build_constructor_argument(
-    field: FieldData,
-    default: Option<TokenStream>,
-    is_named: bool,
-) -> (Option<TokenStream>, TokenStream) {
-    let FieldData {
-        name,
-        field_type,
-        into,
-        ..
-    } = field;
+    if value.peek(LitStr) {
+        let lit: LitStr = value.parse()?;
+        return Ok(RawVisibility::Explicit(lit.parse()?));
+    }
 
-    match default {
-        Some(token) => (None, token),
-        None => {
-            if into {
-                let parameter = quote!(#name: impl ::core::convert::Into<#field_type>);
+    let ident: Ident = value.parse()?;
 
-                let pass_value = if is_named {
-                    quote!(#name: #name.into())
-                } else {
-                    quote!(#name.into())
-                };
+    if ident != "inherit" {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "Expected a visibility like \"pub(crate)\" or 'inherit'.",
+        ));
+    }
 
-                (Some(parameter), pass_value)
-            } else {
-                let parameter = quote!(#name: #field_type);
+    Ok(RawVisibility::Inherit)
+}
 
-                let pass_value = if is_named {
-                    quote!(#name: #name)
-                } else {
-                    quote!(#name)
-                };
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WrapperKind {
+    Box,
+    Rc,
+    Arc,
+    PinBox,
+    ArcMutex,
+}
 
-                (Some(parameter), pass_value)
-            }
+#[derive(Clone)]
+struct ReturnWrapper {
+    kind: WrapperKind,
+    span: Span,
+}
+
+impl ReturnWrapper {
+    fn output(&self) -> TokenStream {
+        let alloc = alloc_root();
+
+        match self.kind {
+            WrapperKind::Box => quote!(#alloc::boxed::Box<Self>),
+            WrapperKind::Rc => quote!(#alloc::rc::Rc<Self>),
+            WrapperKind::Arc => quote!(#alloc::sync::Arc<Self>),
+            WrapperKind::PinBox => quote!(::core::pin::Pin<#alloc::boxed::Box<Self>>),
+            WrapperKind::ArcMutex => quote!(::std::sync::Arc<::std::sync::Mutex<Self>>),
         }
     }
-}
 
-fn generate_constructor(
-    is_named: bool,
-    constructor_field: Vec<TokenStream>,
-    pass_value: Vec<TokenStream>,
-    new_name: Ident,
-    public: TokenStream,
-    constant: TokenStream,
-) -> TokenStream {
-    if constructor_field.is_empty() && pass_value.is_empty() && !is_named {
-        return quote! {
-            #[must_use]
-            #public #constant fn #new_name() -> Self {
-                Self
+    fn wrap(&self, value: TokenStream) -> TokenStream {
+        let alloc = alloc_root();
+
+        match self.kind {
+            WrapperKind::Box => quote!(#alloc::boxed::Box::new(#value)),
+            WrapperKind::Rc => quote!(#alloc::rc::Rc::new(#value)),
+            WrapperKind::Arc => quote!(#alloc::sync::Arc::new(#value)),
+            WrapperKind::PinBox => quote!(#alloc::boxed::Box::pin(#value)),
+            WrapperKind::ArcMutex => {
+                quote!(::std::sync::Arc::new(::std::sync::Mutex::new(#value)))
             }
-        };
+        }
     }
 
-    if is_named {
-        return quote! {
-            #[must_use]
-            #public #constant fn #new_name(#(#constructor_field),*) -> Self {
-                Self {
-                    #(#pass_value),*
-                }
-            }
-        };
+    fn suffix(&self) -> &'static str {
+        match self.kind {
+            WrapperKind::Box => "box",
+            WrapperKind::Rc => "rc",
+            WrapperKind::Arc => "arc",
+            WrapperKind::PinBox => "pin_box",
+            WrapperKind::ArcMutex => "arc_mutex",
+        }
     }
 
-    quote! {
-        #[must_use]
-        #public #constant fn #new_name(#(#constructor_field),*) -> Self {
-            Self(#(#pass_value),* )
+    fn describe(&self) -> &'static str {
+        match self.kind {
+            WrapperKind::Box => "a `Box`",
+            WrapperKind::Rc => "an `Rc`",
+            WrapperKind::Arc => "an `Arc`",
+            WrapperKind::PinBox => "a `Pin<Box>`",
+            WrapperKind::ArcMutex => "an `Arc<Mutex>`",
         }
     }
 }
 
-#[derive(Debug)]
-enum DefaultValue {
-    None,
-    Unit,
-    PhantomData,
-    Trait,
-    CustomFunction(TokenStream),
+fn alloc_root() -> TokenStream {
+    if cfg!(feature = "std") {
+        quote!(::std)
+    } else {
+        quote!(::alloc)
+    }
 }
 
-fn read_field_settings(field: &Field) -> syn::Result<(DefaultValue, bool)> {
-    let mut default_value = DefaultValue::None;
-    let mut into = false;
+fn parse_return_wrapper(
+    meta: &ParseNestedMeta<'_>,
+    returns: &mut Option<ReturnWrapper>,
+    wrapped: &mut Option<ReturnWrapper>,
+) -> syn::Result<()> {
+    let is_returns = meta.path.is_ident("returns");
+    let (key, target, other) = if is_returns {
+        ("returns", returns, wrapped)
+    } else {
+        ("wrapped", wrapped, returns)
+    };
 
-    let mut seen_new_attribute = false;
+    if target.is_some() {
+        return Err(meta.error(format!(
+            "Duplicate '{}' key found in #[new(...)] attribute.",
+            key
+        )));
+    }
 
-    for attribute in &field.attrs {
-        if !attribute.path().is_ident("new") {
-            continue;
-        }
+    if other.is_some() {
+        return Err(meta.error("'returns' and 'wrapped' cannot be combined."));
+    }
 
-        if seen_new_attribute {
+    let wrapper: Type = meta.value()?.parse()?;
+    let kind = match wrapper
+        .to_token_stream()
+        .to_string()
+        .replace(' ', "")
+        .as_str()
+    {
+        "Box" => WrapperKind::Box,
+        "Rc" => WrapperKind::Rc,
+        "Arc" => WrapperKind::Arc,
+        "Pin<Box>" => WrapperKind::PinBox,
+        "Arc<Mutex>" if cfg!(feature = "std") => WrapperKind::ArcMutex,
+        "Arc<Mutex>" => {
             return Err(syn::Error::new_spanned(
-                attribute,
-                "Multiple #[new(...)] attributes found. Ensure the field has only one #[new(...)] attribute.",
-            ));
+                wrapper,
+                "'Arc<Mutex>' requires the 'std' feature.",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                wrapper,
+                "Expected one of 'Box', 'Rc', 'Arc', 'Pin<Box>' or 'Arc<Mutex>'.",
+            ))
         }
+    };
 
-        seen_new_attribute = true;
+    *target = Some(ReturnWrapper {
+        kind,
+        span: wrapper.span(),
+    });
+    Ok(())
+}
 
-        let mut has_arguments = false;
+fn parse_forwarded_attribute(meta: &ParseNestedMeta<'_>) -> syn::Result<Meta> {
+    let content;
+    parenthesized!(content in meta.input);
 
-        attribute.parse_nested_meta(|meta| {
-            has_arguments = true;
-            field_settings_parser(meta, &mut default_value, &mut into)
-        })?;
+    content.parse()
+}
 
-        if !has_arguments {
-            return Err(syn::Error::new_spanned(
-                attribute,
-                "Expected an argument in #[new(...)] attribute.",
-            ));
-        }
-    }
+#[derive(Clone)]
+struct ConstructorProps {
+    pub name: Ident,
+    pub visibility: Visibility,
+    pub constant: bool,
+    pub auto_constant: bool,
+    pub defaults: Vec<(Ident, DefaultValue)>,
+    pub params: Vec<(Ident, Type)>,
+    pub args_struct: bool,
+    pub doc: Option<String>,
+    pub attributes: Vec<Meta>,
+    pub must_use: bool,
+    pub returns: Option<ReturnWrapper>,
+    pub wrapped: Option<ReturnWrapper>,
+}
 
-    if into && !matches!(default_value, DefaultValue::None) {
-        return Err(syn::Error::new_spanned(
-            field,
-            "'into' and 'default' cannot be combined in the same #[new(...)] attribute.",
-        ));
+impl ConstructorProps {
+    fn from_main(name: Ident, props: &MainProps) -> Self {
+        Self {
+            name,
+            visibility: props.visibility.clone(),
+            constant: props.constant,
+            auto_constant: props.auto_constant,
+            defaults: Vec::new(),
+            params: Vec::new(),
+            args_struct: props.args_struct,
+            doc: props.doc.clone(),
+            attributes: props.attributes.clone(),
+            must_use: props.must_use,
+            returns: props.returns.clone(),
+            wrapped: props.wrapped.clone(),
+        }
     }
+}
 
-    detect_automatic_defaults(&mut default_value, field);
-    Ok((default_value, into))
+struct RawConstructorProps {
+    span: Span,
+    name: Option<Ident>,
+    visibility: Option<RawVisibility>,
+    constant: Option<ConstMode>,
+    defaults: Vec<(Ident, DefaultValue)>,
+    params: Vec<(Ident, Type)>,
+    args_struct: bool,
+    doc: Option<String>,
+    attributes: Vec<Meta>,
+    must_use: Option<bool>,
+    returns: Option<ReturnWrapper>,
+    wrapped: Option<ReturnWrapper>,
 }
 
-fn detect_automatic_defaults(default_value: &mut DefaultValue, field: &Field) {
-    if !matches!(&default_value, DefaultValue::None) {
-        return;
+impl Default for RawConstructorProps {
+    fn default() -> Self {
+        Self {
+            span: Span::call_site(),
+            name: None,
+            visibility: None,
+            constant: None,
+            defaults: Vec::new(),
+            params: Vec::new(),
+            args_struct: false,
+            doc: None,
+            attributes: Vec::new(),
+            must_use: None,
+            returns: None,
+            wrapped: None,
+        }
     }
+}
 
-    if is_phantom_data(&field.ty) {
-        *default_value = DefaultValue::PhantomData;
-        return;
+fn constructor_props_parser(
+    meta: ParseNestedMeta<'_>,
+    raw: &mut RawConstructorProps,
+) -> syn::Result<()> {
+    if meta.path.is_ident("name") {
+        if raw.name.is_some() {
+            return Err(meta.error("Duplicate 'name' key found in 'constructor(...)'."));
+        }
+
+        let value = meta.value()?;
+        let lit: LitStr = value.parse()?;
+        raw.name = Some(parse_name(&lit, "constructor")?);
+        return Ok(());
     }
 
-    let Type::Tuple(TypeTuple { elems, .. }) = &field.ty else {
-        return;
-    };
+    if meta.path.is_ident("pub") || meta.path.is_ident("vis") {
+        if raw.visibility.is_some() {
+            return Err(meta.error("Duplicate 'pub' or 'vis' key found in 'constructor(...)'."));
+        }
 
-    if elems.is_empty() {
-        *default_value = DefaultValue::Unit;
+        raw.visibility = Some(parse_visibility(&meta)?);
+        return Ok(());
     }
-}
 
-fn field_settings_parser(
-    meta: ParseNestedMeta<'_>,
-    default_value: &mut DefaultValue,
-    into: &mut bool,
-) -> syn::Result<()> {
-    if meta.path.is_ident("into") {
-        if *into {
-            return Err(meta.error("Duplicate 'into' key found in #[new(...)] attribute."));
+    if meta.path.is_ident("const") {
+        if raw.constant.is_some() {
+            return Err(meta.error("Duplicate 'const' key found in 'constructor(...)'."));
         }
 
-        *into = true;
+        raw.constant = Some(parse_const_mode(&meta)?);
         return Ok(());
     }
 
     if meta.path.is_ident("default") {
-        if !matches!(default_value, DefaultValue::None) {
-            return Err(meta.error("Duplicate 'default' key found in #[new(...)] attribute."));
+        return meta.parse_nested_meta(|meta| {
+            let Some(name) = meta.path.get_ident().cloned() else {
+                return Err(meta.error("Expected a field name in 'default(...)'."));
+            };
+
+            if raw.defaults.iter().any(|(existing, _)| *existing == name) {
+                return Err(meta.error("Duplicate field found in 'default(...)'."));
+            }
+
+            if !meta.input.peek(Token![=]) {
+                raw.defaults.push((name, DefaultValue::Trait));
+                return Ok(());
+            }
+
+            meta.input.parse::<Token![=]>()?;
+            let default_expression: Expr = meta.input.parse()?;
+            raw.defaults.push((
+                name,
+                DefaultValue::CustomFunction(default_expression.into_token_stream()),
+            ));
+            Ok(())
+        });
+    }
+
+    if meta.path.is_ident("doc") {
+        if raw.doc.is_some() {
+            return Err(meta.error("Duplicate 'doc' key found in 'constructor(...)'."));
         }
 
-        if !meta.input.peek(Token![=]) {
-            *default_value = DefaultValue::Trait;
-            return Ok(());
+        let lit: LitStr = meta.value()?.parse()?;
+        raw.doc = Some(lit.value());
+        return Ok(());
+    }
+
+    if meta.path.is_ident("attr") {
+        raw.attributes.push(parse_forwarded_attribute(&meta)?);
+        return Ok(());
+    }
+
+    if meta.path.is_ident("returns") || meta.path.is_ident("wrapped") {
+        return parse_return_wrapper(&meta, &mut raw.returns, &mut raw.wrapped);
+    }
+
+    if meta.path.is_ident("must_use") {
+        if raw.must_use.is_some() {
+            return Err(meta.error("Duplicate 'must_use' key found in 'constructor(...)'."));
         }
 
-        meta.input.parse::<Token![=]>()?;
-        let default_expression: Expr = meta.input.parse()?;
-        *default_value = DefaultValue::CustomFunction(default_expression.into_token_stream());
+        let lit: LitBool = meta.value()?.parse()?;
+        raw.must_use = Some(lit.value);
         return Ok(());
     }
 
-    Err(meta.error("Unknown argument found in #[new(...)] attribute."))
-}
+    if meta.path.is_ident("args_struct") {
+        if raw.args_struct {
+            return Err(meta.error("Duplicate 'args_struct' key found in 'constructor(...)'."));
+        }
 
-fn is_phantom_data(ty: &Type) -> bool {
-    let Type::Path(TypePath { path, .. }) = ty else {
-        return false;
-    };
+        raw.args_struct = true;
+        return Ok(());
+    }
 
-    let Some(last) = path.segments.last() else {
-        return false;
-    };
+    if meta.path.is_ident("params") {
+        let content;
+        parenthesized!(content in meta.input);
+
+        let params = content.parse_terminated(
+            |input| {
+                let name: Ident = input.parse()?;
+                input.parse::<Token![:]>()?;
+                let param_type: Type = input.parse()?;
+                Ok((name, param_type))
+            },
+            Token![,],
+        )?;
+
+        raw.params.extend(params);
+        return Ok(());
+    }
 
-    return last.ident == "PhantomData";
+    Err(meta.error("Unknown argument in 'constructor(...)'."))
 }
 
-struct MainProps {
-    pub public: bool,
-    pub rename: Ident,
-    pub constant: bool,
+struct VariantProps {
+    pub rename: Option<Ident>,
 }
 
-impl MainProps {
+impl VariantProps {
     fn from_attributes(attributes: &[Attribute]) -> syn::Result<Self> {
-        let mut public = None;
         let mut rename = None;
-        let mut constant = None;
 
         let mut seen_new_attribute = false;
 
@@ -407,7 +3660,7 @@ impl MainProps {
             if seen_new_attribute {
                 return Err(syn::Error::new_spanned(
                     attribute,
-                    "Multiple #[new(...)] attributes found. Ensure only one is used on the struct.",
+                    "Multiple #[new(...)] attributes found. Ensure only one is used on the variant.",
                 ));
             }
 
@@ -416,7 +3669,7 @@ impl MainProps {
 
             attribute.parse_nested_meta(|meta| {
                 has_arguments = true;
-                main_props_parser(meta, &mut public, &mut rename, &mut constant)
+                variant_props_parser(meta, &mut rename)
             })?;
 
             if !has_arguments {
@@ -427,31 +3680,11 @@ impl MainProps {
             }
         }
 
-        Ok(Self {
-            public: public.unwrap_or(true),
-            rename: rename.unwrap_or_else(|| Ident::new("new", Span::call_site())),
-            constant: constant.unwrap_or(false),
-        })
+        Ok(Self { rename })
     }
 }
 
-fn main_props_parser(
-    meta: ParseNestedMeta<'_>,
-    public: &mut Option<bool>,
-    rename: &mut Option<Ident>,
-    constant: &mut Option<bool>,
-) -> syn::Result<()> {
-    if meta.path.is_ident("pub") {
-        if public.is_some() {
-            return Err(meta.error("Duplicate 'pub' key found in #[new(...)] attribute."));
-        }
-
-        let value = meta.value()?;
-        let lit: LitBool = value.parse()?;
-        *public = Some(lit.value);
-        return Ok(());
-    }
-
+fn variant_props_parser(meta: ParseNestedMeta<'_>, rename: &mut Option<Ident>) -> syn::Result<()> {
     if meta.path.is_ident("rename") {
         if rename.is_some() {
             return Err(meta.error("Duplicate 'rename' key found in #[new(...)] attribute."));
@@ -459,22 +3692,96 @@ fn main_props_parser(
 
         let value = meta.value()?;
         let lit: LitStr = value.parse()?;
-        *rename = Some(Ident::new(&lit.value(), lit.span()));
+        *rename = Some(parse_name(&lit, "constructor")?);
         return Ok(());
     }
 
-    if meta.path.is_ident("const") {
-        if constant.is_some() {
-            return Err(meta.error("Duplicate 'const' key found in #[new(...)] attribute."));
-        }
+    Err(meta.error("Unknown argument in #[new(...)] attribute."))
+}
 
-        let value = meta.value()?;
-        let lit: LitBool = value.parse()?;
-        *constant = Some(lit.value);
-        return Ok(());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn method_docs(input: DeriveInput, method: &str) -> Vec<String> {
+        let DeriveInput {
+            ident,
+            vis,
+            data,
+            generics,
+            attrs,
+        } = input;
+        let output = derive_new_impl(ident, vis, data, generics, attrs).unwrap();
+        let file: syn::File = syn::parse2(output).unwrap();
+
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item) => Some(&item.items),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|item| match item {
+                syn::ImplItem::Fn(function) if function.sig.ident == method => {
+                    Some(read_docs(&function.attrs))
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn default_docs_show_written_expressions() {
+        let docs = method_docs(
+            parse_quote! {
+                #[new(const = true, with)]
+                struct A {
+                    #[new(default = K < 4 && !false)]
+                    a: bool,
+                    #[new(default = String::from("a (b)"))]
+                    b: String,
+                    #[new(optional)]
+                    c: Option<u32>,
+                    #[new(repeat = 0)]
+                    d: [u8; 4],
+                    #[new(default)]
+                    e: Vec<u8>,
+                }
+            },
+            "new",
+        );
+
+        let defaults = docs.iter().skip_while(|line| *line != "# Defaults");
+        let defaults: Vec<_> = defaults.skip(2).take(5).map(String::as_str).collect();
+        assert_eq!(
+            defaults,
+            [
+                "* `a` - `K < 4 && !false`",
+                "* `b` - `String::from(\"a (b)\")`",
+                "* `c` - `None`",
+                "* `d` - `0` for every element",
+                "* `e` - `Default::default()`",
+            ]
+        );
     }
 
-    return Err(meta.error("Unknown argument in #[new(...)] attribute."));
+    #[test]
+    fn wither_docs_show_written_expressions() {
+        let docs = method_docs(
+            parse_quote! {
+                struct A {
+                    #[new(from_fn = |i| i * 2, with)]
+                    a: [usize; 3],
+                }
+            },
+            "with_a",
+        );
+
+        assert_eq!(
+            docs[0],
+            "Replaces the `a` field, which defaults to `core::array::from_fn(|i| i * 2)`."
+        );
+    }
 }
 
 #[doc = include_str!("../README.md")]
@@ -0,0 +1,148 @@
+use inew::New;
+
+#[test]
+fn struct_args_struct() {
+    #[derive(New)]
+    #[new(args_struct)]
+    struct A {
+        host: String,
+        port: u16,
+        #[new(default = 30)]
+        timeout: u32,
+    }
+
+    let res = A::new(AArgs {
+        host: "localhost".to_string(),
+        port: 80,
+    });
+    assert_eq!(res.host, "localhost");
+    assert_eq!(res.port, 80);
+    assert_eq!(res.timeout, 30);
+}
+
+#[test]
+fn unit_like_struct_args_struct() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(args_struct)]
+    struct A;
+
+    let res = A::new(AArgs {});
+    assert_eq!(res, A);
+}
+
+#[test]
+fn struct_args_struct_constructor() {
+    #[derive(New)]
+    #[new(args_struct)]
+    struct A {
+        #[new(into)]
+        host: String,
+        #[new(into_iter)]
+        ports: Vec<u16>,
+        #[new(some)]
+        user: Option<String>,
+    }
+
+    let res = A::new(AArgs::new("localhost", [80, 443], "admin".to_string()));
+    assert_eq!(res.host, "localhost");
+    assert_eq!(res.ports, vec![80, 443]);
+    assert_eq!(res.user.as_deref(), Some("admin"));
+}
+
+#[test]
+fn struct_args_struct_with_generics_and_lifetimes() {
+    #[derive(New)]
+    #[new(args_struct)]
+    struct A<'a, T: Clone, U>
+    where
+        U: Default,
+    {
+        x: &'a T,
+        #[new(default)]
+        y: U,
+    }
+
+    let value = 1u32;
+    let res: A<u32, u64> = A::new(AArgs { x: &value });
+    assert_eq!(*res.x, 1);
+    assert_eq!(res.y, 0);
+}
+
+#[test]
+fn struct_args_struct_with_validation() {
+    fn check_port(port: &u16) -> Result<(), String> {
+        if *port == 0 {
+            return Err("port must not be zero".to_string());
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, New)]
+    #[new(args_struct, error = String)]
+    struct A {
+        #[new(validate = check_port)]
+        port: u16,
+    }
+
+    let res = A::new(AArgs { port: 80 }).unwrap();
+    assert_eq!(res.port, 80);
+
+    let res = A::new(AArgs { port: 0 }).unwrap_err();
+    assert_eq!(res, "port must not be zero");
+}
+
+#[test]
+fn struct_args_struct_const() {
+    #[derive(New)]
+    #[new(args_struct, const = true)]
+    struct A {
+        x: u32,
+        #[new(default = 2)]
+        y: u32,
+    }
+
+    const RES: A = A::new(AArgs::new(1));
+    assert_eq!(RES.x, 1);
+    assert_eq!(RES.y, 2);
+}
+
+#[test]
+fn struct_args_struct_multiple_constructors() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "scaled", args_struct, default(y = scale * 2), params(scale: u32))
+    )]
+    struct A {
+        x: u32,
+        y: u32,
+    }
+
+    let res = A::new(1, 2);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+
+    let res = A::scaled(AScaledArgs { x: 2, scale: 3 });
+    assert_eq!(res.x, 2);
+    assert_eq!(res.y, 6);
+}
+
+#[test]
+fn struct_args_struct_with_shadowed_generic_names() {
+    mod a {
+        #[derive(Debug, PartialEq)]
+        pub struct T(pub u32);
+    }
+
+    #[derive(New)]
+    #[new(args_struct)]
+    struct A<'a, T> {
+        x: a::T,
+        #[new(default)]
+        y: std::marker::PhantomData<&'a T>,
+    }
+
+    let res: A<u64> = A::new(AArgs { x: a::T(1) });
+    assert_eq!(res.x, a::T(1));
+}
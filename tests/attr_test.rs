@@ -0,0 +1,136 @@
+use inew::New;
+use std::cell::Cell;
+
+thread_local! {
+    static CREATED: Cell<u32> = const { Cell::new(0) };
+}
+
+fn register() -> u32 {
+    CREATED.with(|created| created.set(created.get() + 1));
+    CREATED.with(Cell::get)
+}
+
+#[test]
+fn struct_with_forwarded_attributes() {
+    #[derive(New)]
+    #[new(attr(inline), attr(allow(clippy::too_many_arguments)))]
+    struct A {
+        a: u8,
+        b: u8,
+        c: u8,
+        d: u8,
+        e: u8,
+        f: u8,
+        g: u8,
+        h: u8,
+    }
+
+    let res = A::new(1, 2, 3, 4, 5, 6, 7, 8);
+    assert_eq!(
+        [res.a, res.b, res.c, res.d, res.e, res.f, res.g, res.h],
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+}
+
+#[test]
+fn tuple_struct_with_forwarded_attributes() {
+    #[derive(New)]
+    #[new(
+        attr(inline(always)),
+        attr(cfg_attr(test, allow(dead_code))),
+        attr(doc(hidden))
+    )]
+    struct A(u32, #[new(default = 2)] u64);
+
+    let res = A::new(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_with_forwarded_cfg() {
+    #[derive(New)]
+    #[new(attr(cfg(all())))]
+    struct A {
+        x: u32,
+    }
+
+    assert_eq!(A::new(1).x, 1);
+}
+
+#[test]
+fn struct_with_track_caller() {
+    #[derive(Debug, New)]
+    #[new(attr(track_caller))]
+    struct A {
+        #[new(value = std::panic::Location::caller().line())]
+        line: u32,
+    }
+
+    let res = A::new();
+    assert_eq!(res.line, line!() - 1);
+}
+
+#[test]
+fn struct_without_must_use() {
+    #[derive(New)]
+    #[new(must_use = false)]
+    struct A {
+        #[new(value = register())]
+        id: u32,
+    }
+
+    A::new();
+    assert!(A::new().id > 1);
+}
+
+#[test]
+fn tuple_struct_without_must_use() {
+    #[derive(New)]
+    #[new(must_use = false)]
+    struct A(#[new(value = register())] u32);
+
+    A::new();
+    assert!(A::new().0 > 1);
+}
+
+#[test]
+fn struct_with_forwarded_must_use_message() {
+    #[derive(New)]
+    #[new(attr(must_use = "the value is registered"))]
+    struct A {
+        x: u32,
+    }
+
+    assert_eq!(A::new(1).x, 1);
+}
+
+#[test]
+fn struct_with_constructor_attributes() {
+    #[derive(New)]
+    #[new(
+        attr(inline),
+        constructor(name = "new"),
+        constructor(name = "register", must_use = false, attr(cold), default(id = register())),
+    )]
+    struct A {
+        id: u32,
+    }
+
+    A::register();
+    assert_eq!(A::new(5).id, 5);
+    assert!(A::register().id > 1);
+}
+
+#[test]
+fn enum_with_forwarded_attributes() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(attr(inline), must_use = false)]
+    enum A {
+        B(u32),
+        C,
+    }
+
+    A::new_c();
+    assert_eq!(A::new_b(1), A::B(1));
+}
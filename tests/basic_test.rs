@@ -581,6 +581,7 @@ fn struct_with_static_lifetime() {
 }
 
 #[test]
+#[allow(clippy::needless_borrow)]
 fn tuple_struct_with_static_lifetime() {
     const X: &str = "abc";
 
@@ -0,0 +1,354 @@
+use inew::Builder;
+use std::marker::PhantomData;
+
+#[test]
+fn struct_builder() {
+    #[derive(Debug, Builder)]
+    struct A {
+        x: u32,
+        y: u64,
+    }
+
+    let res = A::builder().x(1).y(2).build().unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn tuple_struct_builder() {
+    #[derive(Debug, Builder)]
+    struct A(u32, u64);
+
+    let res = A::builder()._0(1)._1(2).build().unwrap();
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn unit_like_struct_builder() {
+    #[derive(Debug, PartialEq, Builder)]
+    struct A;
+
+    let res = A::builder().build().unwrap();
+    assert_eq!(res, A);
+}
+
+#[test]
+fn struct_builder_setters_in_any_order() {
+    #[derive(Debug, Builder)]
+    struct A {
+        x: u32,
+        y: u64,
+    }
+
+    let res = A::builder().y(2).x(1).build().unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn struct_builder_missing_field() {
+    #[derive(Debug, Builder)]
+    struct A {
+        x: u32,
+        y: u64,
+    }
+
+    let error = A::builder().x(1).build().unwrap_err();
+    assert_eq!(error.field(), "y");
+    assert_eq!(error.to_string(), "missing required field `y`");
+
+    let error = A::builder().build().unwrap_err();
+    assert_eq!(error.field(), "x");
+
+    let res = A::builder().x(1).y(2).build().unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn tuple_struct_builder_missing_field() {
+    #[derive(Debug, Builder)]
+    struct A(u32, u64);
+
+    let error = A::builder()._0(1).build().unwrap_err();
+    assert_eq!(error.field(), "_1");
+
+    let res = A::builder()._0(1)._1(2).build().unwrap();
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_builder_with_defaults() {
+    fn custom_default() -> u8 {
+        5
+    }
+
+    #[derive(Debug, Builder)]
+    struct A {
+        x: u32,
+        #[new(default)]
+        y: u64,
+        #[new(default = custom_default())]
+        z: u8,
+    }
+
+    let res = A::builder().x(1).build().unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 0);
+    assert_eq!(res.z, 5);
+
+    let res = A::builder().x(1).y(2).z(3).build().unwrap();
+    assert_eq!(res.y, 2);
+    assert_eq!(res.z, 3);
+}
+
+#[test]
+fn tuple_struct_builder_with_defaults() {
+    #[derive(Debug, Builder)]
+    struct A(u32, #[new(default)] u64, #[new(default = 1 + 2)] u8);
+
+    let res = A::builder()._0(1).build().unwrap();
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 0);
+    assert_eq!(res.2, 3);
+}
+
+#[test]
+fn struct_builder_auto_defaults() {
+    #[derive(Debug, Builder)]
+    struct A<T> {
+        x: (),
+        y: PhantomData<T>,
+    }
+
+    let res: A<u32> = A::builder().build().unwrap();
+    assert_eq!(res.x, ());
+    assert_eq!(res.y, PhantomData);
+}
+
+#[test]
+fn struct_builder_into_and_into_iter() {
+    #[derive(Debug, Builder)]
+    struct A {
+        #[new(into)]
+        name: String,
+        #[new(into_iter)]
+        items: Vec<u32>,
+    }
+
+    let res = A::builder().name("abc").items(1..3).build().unwrap();
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.items, vec![1, 2]);
+}
+
+#[test]
+fn struct_builder_optional_and_some() {
+    #[derive(Debug, Builder)]
+    struct A {
+        #[new(some)]
+        x: Option<u32>,
+        #[new(optional)]
+        y: Option<u32>,
+    }
+
+    let res = A::builder().x(1).build().unwrap();
+    assert_eq!(res.x, Some(1));
+    assert_eq!(res.y, None);
+
+    let res = A::builder().y(2).build().unwrap_err();
+    assert_eq!(res.field(), "x");
+}
+
+#[test]
+fn struct_builder_with_generics_and_lifetimes() {
+    #[derive(Debug, Builder)]
+    struct A<'a, T: Clone> {
+        x: &'a T,
+        #[new(default)]
+        y: Option<T>,
+    }
+
+    let x = 1u64;
+    let res = A::builder().x(&x).build().unwrap();
+    assert_eq!(*res.x, 1);
+    assert_eq!(res.y, None);
+}
+
+#[test]
+fn struct_builder_and_new() {
+    use inew::New;
+
+    #[derive(Debug, New, Builder)]
+    #[new(pub = false)]
+    struct A {
+        #[new(into)]
+        x: String,
+        #[new(default = 7)]
+        y: u8,
+    }
+
+    let res = A::new("abc");
+    assert_eq!(res.x, "abc");
+    assert_eq!(res.y, 7);
+
+    let res = A::builder().x("def").y(8).build().unwrap();
+    assert_eq!(res.x, "def");
+    assert_eq!(res.y, 8);
+}
+
+#[test]
+fn struct_typestate_builder() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A {
+        x: u32,
+        y: u64,
+    }
+
+    let res = A::builder().x(1).y(2).build();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+
+    let res = A::builder().y(2).x(1).build();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn tuple_struct_typestate_builder() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A(u32, u64);
+
+    let res = A::builder()._1(2)._0(1).build();
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn unit_like_struct_typestate_builder() {
+    #[derive(Debug, PartialEq, Builder)]
+    #[new(builder = typestate)]
+    struct A;
+
+    assert_eq!(A::builder().build(), A);
+}
+
+#[test]
+fn struct_typestate_builder_with_defaults() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A {
+        #[new(default)]
+        x: u32,
+        y: u64,
+        #[new(default = 1 + 2)]
+        z: u8,
+    }
+
+    let res = A::builder().y(2).build();
+    assert_eq!(res.x, 0);
+    assert_eq!(res.y, 2);
+    assert_eq!(res.z, 3);
+
+    let res = A::builder().z(5).y(2).x(1).build();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+    assert_eq!(res.z, 5);
+}
+
+#[test]
+fn tuple_struct_typestate_builder_with_defaults() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A(#[new(default)] u32, u64);
+
+    let res = A::builder()._1(2).build();
+    assert_eq!(res.0, 0);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_typestate_builder_into_and_into_iter() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A {
+        #[new(into)]
+        name: String,
+        #[new(into_iter)]
+        items: Vec<u32>,
+    }
+
+    let res = A::builder().items(1..3).name("abc").build();
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.items, vec![1, 2]);
+}
+
+#[test]
+fn struct_typestate_builder_optional_and_some() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A {
+        #[new(some)]
+        x: Option<u32>,
+        #[new(optional)]
+        y: Option<u32>,
+    }
+
+    let res = A::builder().y(2).x(1).build();
+    assert_eq!(res.x, Some(1));
+    assert_eq!(res.y, Some(2));
+}
+
+#[test]
+fn struct_typestate_builder_with_generics_and_lifetimes() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A<'a, T: Clone, const N: usize, U = u8>
+    where
+        U: Default,
+    {
+        x: &'a T,
+        y: [T; N],
+        #[new(default)]
+        z: U,
+        marker: PhantomData<T>,
+    }
+
+    let x = 1u64;
+    let res: A<u64, 2> = A::builder().y([2, 3]).x(&x).build();
+    assert_eq!(*res.x, 1);
+    assert_eq!(res.y, [2, 3]);
+    assert_eq!(res.z, 0);
+    assert_eq!(res.marker, PhantomData);
+}
+
+#[test]
+fn struct_builder_renamed_build_setter() {
+    #[derive(Debug, Builder)]
+    struct A {
+        #[new(name = "build_number")]
+        build: u32,
+    }
+
+    let res = A::builder().build_number(3).build().unwrap();
+    assert_eq!(res.build, 3);
+}
+
+#[test]
+fn struct_typestate_builder_renamed_build_setter() {
+    #[derive(Debug, Builder)]
+    #[new(builder = typestate)]
+    struct A {
+        #[new(name = "build_number")]
+        build: u32,
+        #[new(default)]
+        other: u32,
+    }
+
+    let res = A::builder().build_number(3).build();
+    assert_eq!(res.build, 3);
+    assert_eq!(res.other, 0);
+}
@@ -0,0 +1,223 @@
+use inew::New;
+use std::marker::PhantomData;
+
+#[test]
+fn struct_const_auto() {
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A<T> {
+        x: u32,
+        #[new(default)]
+        y: Option<String>,
+        #[new(default = 1 + 2)]
+        z: u64,
+        #[new(default = Point { x: 1, y: -1 })]
+        point: Point,
+        #[new(value = Some(x * 2))]
+        double: Option<u32>,
+        marker: PhantomData<T>,
+    }
+
+    const RES: A<u8> = A::new(1);
+    assert_eq!(RES.x, 1);
+    assert_eq!(RES.y, None);
+    assert_eq!(RES.z, 3);
+    assert_eq!(RES.point, Point { x: 1, y: -1 });
+    assert_eq!(RES.double, Some(2));
+    assert_eq!(RES.marker, PhantomData);
+}
+
+#[test]
+fn tuple_struct_const_auto() {
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A(
+        u32,
+        #[new(default = [0; 3])] [u8; 3],
+        #[new(some)] Option<u64>,
+    );
+
+    const RES: A = A::new(1, 2);
+    assert_eq!(RES.0, 1);
+    assert_eq!(RES.1, [0; 3]);
+    assert_eq!(RES.2, Some(2));
+}
+
+#[test]
+fn struct_const_auto_falls_back() {
+    fn custom_default() -> u64 {
+        3
+    }
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A {
+        #[new(into)]
+        x: String,
+    }
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct B {
+        x: u32,
+        #[new(default = custom_default())]
+        y: u64,
+        #[new(default)]
+        z: std::collections::HashMap<u32, u32>,
+    }
+
+    let res = A::new("abc");
+    assert_eq!(res.x, "abc");
+
+    let res = B::new(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 3);
+    assert!(res.z.is_empty());
+}
+
+#[test]
+fn tuple_struct_const_auto_falls_back() {
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A(#[new(try_into)] u8);
+
+    #[derive(New)]
+    #[new(const = auto, returns = Box)]
+    struct B(u8);
+
+    assert_eq!(A::new(1u32).unwrap().0, 1);
+    assert_eq!(B::new(2).0, 2);
+}
+
+#[test]
+fn struct_const_auto_per_constructor() {
+    #[derive(New)]
+    #[new(
+        const = auto,
+        constructor(name = "new"),
+        constructor(name = "with_name", default(y = String::from("abc"))),
+        constructor(name = "empty", const = false, default(y = String::new())),
+    )]
+    struct A {
+        x: u32,
+        y: String,
+    }
+
+    const RES: A = A::new(1, String::new());
+    assert_eq!(RES.x, 1);
+    assert_eq!(RES.y, "");
+
+    let res = A::with_name(2);
+    assert_eq!(res.y, "abc");
+
+    let res = A::empty(3);
+    assert_eq!(res.x, 3);
+    assert_eq!(res.y, "");
+}
+
+#[test]
+fn enum_const_auto() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(const = auto)]
+    enum A {
+        B(u32),
+        C(#[new(into)] String),
+    }
+
+    const RES: A = A::new_b(1);
+    assert_eq!(RES, A::B(1));
+    assert_eq!(A::new_c("abc"), A::C("abc".to_owned()));
+}
+
+#[test]
+fn struct_const_auto_with_non_std_default() {
+    mod custom {
+        #[derive(Debug, Default, PartialEq)]
+        pub struct Duration(pub u64);
+    }
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A {
+        x: u32,
+        #[new(default)]
+        y: custom::Duration,
+        #[new(default)]
+        z: std::time::Duration,
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, custom::Duration(0));
+    assert_eq!(res.z, std::time::Duration::ZERO);
+}
+
+#[test]
+fn struct_const_auto_with_non_primitive_operator() {
+    use std::time::{Duration, Instant};
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct Deadline {
+        start: Instant,
+        timeout: Duration,
+        #[new(value = start + timeout)]
+        end: Instant,
+    }
+
+    let start = Instant::now();
+    let res = Deadline::new(start, Duration::from_secs(1));
+    assert_eq!(res.end - res.start, res.timeout);
+}
+
+#[test]
+fn tuple_struct_const_auto_with_non_primitive_operator() {
+    use std::time::Duration;
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A(Duration, #[new(value = _0 * 2)] Duration, #[new(default = 1 + 2)] u32);
+
+    let res = A::new(Duration::from_secs(1));
+    assert_eq!(res.1, res.0 * 2);
+    assert_eq!(res.2, 3);
+}
+
+#[test]
+fn struct_const_auto_with_static_default() {
+    static LIMIT: u32 = 5;
+    const MINIMUM: u32 = 1;
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A {
+        #[new(default = LIMIT)]
+        x: u32,
+        #[new(default = MINIMUM)]
+        y: u32,
+        #[new(default = None)]
+        z: Option<u32>,
+    }
+
+    let res = A::new();
+    assert_eq!(res.x, 5);
+    assert_eq!(res.y, 1);
+    assert_eq!(res.z, None);
+}
+
+#[test]
+fn tuple_struct_const_auto_with_primitive_constant() {
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A(#[new(default = u32::MAX)] u32, #[new(default = None)] Option<u8>);
+
+    const RES: A = A::new();
+    assert_eq!(RES.0, u32::MAX);
+    assert_eq!(RES.1, None);
+}
@@ -403,6 +403,7 @@ fn struct_with_static_lifetime() {
 }
 
 #[test]
+#[allow(clippy::needless_borrow)]
 fn tuple_struct_with_static_lifetime() {
     const X: &str = "abc";
 
@@ -461,3 +462,162 @@ fn tuple_struct_rename_new() {
     const RES: A<u64> = A::create(&X);
     assert_eq!(*RES.0, 1);
 }
+
+#[test]
+fn struct_with_const_std_defaults() {
+    use std::cell::Cell;
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[derive(New)]
+    #[new(const = true)]
+    struct A {
+        x: u32,
+        #[new(default)]
+        a: i64,
+        #[new(default)]
+        b: f64,
+        #[new(default)]
+        c: bool,
+        #[new(default)]
+        d: char,
+        #[new(default)]
+        e: Option<String>,
+        #[new(default)]
+        f: String,
+        #[new(default)]
+        g: Vec<u8>,
+        #[new(default)]
+        h: &'static str,
+        #[new(default)]
+        i: &'static [u8],
+        #[new(default)]
+        j: [Option<String>; 3],
+        #[new(default)]
+        k: (u8, bool),
+        #[new(default)]
+        m: BTreeMap<u8, u8>,
+        #[new(default)]
+        n: Duration,
+    }
+
+    #[derive(New)]
+    #[new(const = true)]
+    struct B {
+        #[new(default)]
+        x: AtomicUsize,
+        #[new(default)]
+        y: AtomicBool,
+        #[new(default)]
+        z: Cell<u32>,
+    }
+
+    const RES: A = A::new(1);
+    let res = RES;
+    assert_eq!(res.x, 1);
+    assert_eq!(res.a, 0);
+    assert_eq!(res.b, 0.0);
+    assert!(!res.c);
+    assert_eq!(res.d, '\0');
+    assert_eq!(res.e, None);
+    assert_eq!(res.f, "");
+    assert!(res.g.is_empty());
+    assert_eq!(res.h, "");
+    assert!(res.i.is_empty());
+    assert_eq!(res.j, [None, None, None]);
+    assert_eq!(res.k, (0, false));
+    assert!(res.m.is_empty());
+    assert_eq!(res.n, Duration::ZERO);
+
+    let res = B::new();
+    assert_eq!(res.z.get(), 0);
+
+    res.x.fetch_add(1, Ordering::Relaxed);
+    assert_eq!(res.x.load(Ordering::Relaxed), 1);
+    assert!(!res.y.load(Ordering::Relaxed));
+}
+
+#[test]
+fn struct_with_const_owned_string_defaults() {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    #[derive(New)]
+    #[new(const = true)]
+    struct A {
+        #[new(default)]
+        x: String,
+    }
+
+    // `OsString` and `PathBuf` have no const default, so an automatic constructor isn't `const`.
+    #[derive(New)]
+    #[new(const = auto)]
+    struct B {
+        #[new(default)]
+        x: OsString,
+        #[new(default)]
+        y: PathBuf,
+    }
+
+    const RES: A = A::new();
+    let res = RES;
+    assert_eq!(res.x, "");
+
+    let res = B::new();
+    assert!(res.x.is_empty());
+    assert_eq!(res.y, PathBuf::new());
+}
+
+#[test]
+fn tuple_struct_with_const_std_defaults() {
+    use std::sync::Mutex;
+
+    #[derive(New)]
+    #[new(const = true)]
+    struct A(
+        u32,
+        #[new(default)] Mutex<Vec<u8>>,
+        #[new(default)] [u16; 4],
+    );
+
+    static RES: A = A::new(1);
+    RES.1.lock().unwrap().push(2);
+    assert_eq!(RES.0, 1);
+    assert_eq!(*RES.1.lock().unwrap(), vec![2]);
+    assert_eq!(RES.2, [0; 4]);
+}
+
+#[test]
+fn struct_with_default_and_generics() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A<T> {
+        x: [Option<T>; 4],
+        #[new(default = 0)]
+        y: usize,
+        #[new(default)]
+        z: Option<T>,
+    }
+
+    const RES: A<u32> = A::new([Some(1), None, None, None]);
+    assert_eq!(RES.x, [Some(1), None, None, None]);
+    assert_eq!(RES.y, 0);
+    assert_eq!(RES.z, None);
+}
+
+#[test]
+fn tuple_struct_with_default_and_generics() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A<T, const N: usize>(
+        T,
+        #[new(default)] [Option<T>; N],
+        #[new(default = N * 2)] usize,
+    );
+
+    const RES: A<u8, 2> = A::new(1);
+    assert_eq!(RES.0, 1);
+    assert_eq!(RES.1, [None, None]);
+    assert_eq!(RES.2, 4);
+}
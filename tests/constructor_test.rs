@@ -0,0 +1,165 @@
+use inew::New;
+
+#[test]
+fn struct_multiple_constructors() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(
+            name = "with_capacity",
+            default(buf = Vec::with_capacity(cap)),
+            params(cap: usize)
+        )
+    )]
+    struct A {
+        name: &'static str,
+        buf: Vec<u8>,
+    }
+
+    let res = A::new("abc", vec![1]);
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.buf, vec![1]);
+
+    let res = A::with_capacity("abc", 16);
+    assert_eq!(res.name, "abc");
+    assert!(res.buf.is_empty());
+    assert!(res.buf.capacity() >= 16);
+}
+
+#[test]
+fn tuple_struct_multiple_constructors() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "empty", default(_1)),
+        constructor(name = "filled", default(_1 = vec![value; len]), params(value: u8, len: usize))
+    )]
+    struct A(&'static str, Vec<u8>);
+
+    let res = A::new("abc", vec![1]);
+    assert_eq!(res.0, "abc");
+    assert_eq!(res.1, vec![1]);
+
+    let res = A::empty("abc");
+    assert!(res.1.is_empty());
+
+    let res = A::filled("abc", 7, 2);
+    assert_eq!(res.1, vec![7, 7]);
+}
+
+#[test]
+fn struct_constructors_override_defaults() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "with_y", default(y = 10), default(z))
+    )]
+    struct A {
+        x: u32,
+        #[new(default = 5)]
+        y: u64,
+        #[new(into)]
+        z: String,
+    }
+
+    let res = A::new(1, "abc");
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 5);
+    assert_eq!(res.z, "abc");
+
+    let res = A::with_y(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 10);
+    assert_eq!(res.z, "");
+}
+
+#[test]
+fn struct_constructors_with_own_privacy_and_const() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(
+            pub = false,
+            constructor(name = "new", pub = true),
+            constructor(name = "zero", const = true, default(x = 0))
+        )]
+        pub struct A {
+            pub x: u32,
+        }
+
+        impl A {
+            pub const ZERO: A = A::zero();
+        }
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(inner::A::ZERO.x, 0);
+}
+
+#[test]
+fn struct_constructors_inherit_container_const() {
+    #[derive(New)]
+    #[new(
+        const = true,
+        constructor(name = "new"),
+        constructor(name = "one", default(x = 1)),
+        constructor(name = "runtime", const = false, default(x = "2".parse().unwrap()))
+    )]
+    struct A {
+        x: u32,
+    }
+
+    const NEW: A = A::new(3);
+    const ONE: A = A::one();
+    assert_eq!(NEW.x, 3);
+    assert_eq!(ONE.x, 1);
+    assert_eq!(A::runtime().x, 2);
+}
+
+#[test]
+fn struct_constructors_with_try_into() {
+    #[derive(Debug, New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "checked", default(y = 0))
+    )]
+    struct A {
+        #[new(try_into)]
+        x: u8,
+        #[new(try_into)]
+        y: u8,
+    }
+
+    let res = A::new(1u32, 2u32).unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+    assert!(matches!(A::new(1u32, 300u32), Err(ANewError::Y(_))));
+
+    let res = A::checked(1u32).unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 0);
+    assert!(A::checked(300u32).is_err());
+}
+
+#[test]
+fn struct_constructors_with_generics() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "from_value", default(y = value.clone()), params(value: T))
+    )]
+    struct A<T: Clone> {
+        x: T,
+        y: T,
+    }
+
+    let res = A::new(1, 2);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+
+    let res = A::from_value(1, 3);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 3);
+}
@@ -0,0 +1,140 @@
+#![deny(missing_docs)]
+//! Generated constructors must be documented, so `missing_docs` is denied for this crate.
+
+use inew::New;
+
+/// Structs with documented fields.
+pub mod documented {
+    use inew::New;
+
+    /// A point.
+    #[derive(New)]
+    #[new(doc = "Creates a point on the x axis.")]
+    pub struct Point {
+        /// The x coordinate.
+        ///
+        /// Can be negative.
+        pub x: i32,
+        /// The y coordinate.
+        #[new(default)]
+        pub y: i32,
+    }
+
+    /// A tuple point.
+    #[derive(New)]
+    #[new(const = true)]
+    pub struct TuplePoint(
+        /// The x coordinate.
+        pub i32,
+        /// The y coordinate.
+        #[new(value = _0 * 2)]
+        pub i32,
+    );
+
+    /// A shape.
+    #[derive(New)]
+    #[new(doc = "Creates a shape.")]
+    pub enum Shape {
+        /// A circle.
+        Circle {
+            /// The radius.
+            radius: u32,
+        },
+        /// A square.
+        Square(
+            /// The side.
+            u32,
+        ),
+    }
+}
+
+/// Structs with generated companion items.
+pub mod companions {
+    use inew::New;
+
+    /// A user.
+    #[derive(New)]
+    #[new(args_struct, with)]
+    pub struct User {
+        /// The name.
+        #[new(into)]
+        pub name: String,
+        /// The age.
+        #[new(default)]
+        pub age: u32,
+    }
+
+    /// A fallible tuple.
+    #[derive(New)]
+    #[new(constructor(name = "create", doc = "Creates the tuple from any integer."))]
+    pub struct Small(
+        /// The value.
+        #[new(try_into)]
+        pub u8,
+    );
+}
+
+#[test]
+fn struct_with_doc() {
+    let res = documented::Point::new(-1);
+    assert_eq!(res.x, -1);
+    assert_eq!(res.y, 0);
+}
+
+#[test]
+fn tuple_struct_with_doc() {
+    const RES: documented::TuplePoint = documented::TuplePoint::new(2);
+    assert_eq!(RES.0, 2);
+    assert_eq!(RES.1, 4);
+}
+
+#[test]
+fn enum_with_doc() {
+    let res = documented::Shape::new_circle(1);
+    assert!(matches!(res, documented::Shape::Circle { radius: 1 }));
+
+    let res = documented::Shape::new_square(2);
+    assert!(matches!(res, documented::Shape::Square(2)));
+}
+
+#[test]
+fn struct_with_documented_companions() {
+    let args = companions::UserArgs::new("abc");
+    let res = companions::User::new(args).with_age(3);
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.age, 3);
+}
+
+#[test]
+fn tuple_struct_with_documented_companions() {
+    let res = companions::Small::create(3u64).unwrap();
+    assert_eq!(res.0, 3);
+
+    assert!(companions::Small::create(300u64).is_err());
+}
+
+#[test]
+fn struct_with_constructor_doc() {
+    /// A private struct.
+    #[derive(New)]
+    #[new(
+        doc = "Default summary.",
+        constructor(name = "first", doc = "Overridden summary."),
+        constructor(name = "second")
+    )]
+    struct A {
+        x: u32,
+    }
+
+    assert_eq!(A::first(1).x, 1);
+    assert_eq!(A::second(2).x, 2);
+}
+
+#[test]
+fn tuple_struct_with_constructor_doc() {
+    #[derive(New)]
+    #[new(constructor(name = "create", doc = "Creates a value.\n\nSpans multiple lines."))]
+    struct A(u32);
+
+    assert_eq!(A::create(1).0, 1);
+}
@@ -0,0 +1,199 @@
+use inew::New;
+use std::marker::PhantomData;
+
+#[test]
+fn enum_unit_variant() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Empty,
+    }
+
+    let res = A::new_empty();
+    assert_eq!(res, A::Empty);
+}
+
+#[test]
+fn enum_struct_variant() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Point { x: u32, y: u64 },
+    }
+
+    let res = A::new_point(1, 2);
+    assert_eq!(res, A::Point { x: 1, y: 2 });
+}
+
+#[test]
+fn enum_tuple_variant() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Pair(u32, u64),
+    }
+
+    let res = A::new_pair(1, 2);
+    assert_eq!(res, A::Pair(1, 2));
+}
+
+#[test]
+fn enum_multiple_variants() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Empty,
+        Single(u32),
+        Point { x: u32, y: u64 },
+    }
+
+    assert_eq!(A::new_empty(), A::Empty);
+    assert_eq!(A::new_single(1), A::Single(1));
+    assert_eq!(A::new_point(1, 2), A::Point { x: 1, y: 2 });
+}
+
+#[test]
+fn enum_snake_case_names() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        HttpRequest(u32),
+        IOError,
+        Version2Ready,
+    }
+
+    assert_eq!(A::new_http_request(1), A::HttpRequest(1));
+    assert_eq!(A::new_io_error(), A::IOError);
+    assert_eq!(A::new_version2_ready(), A::Version2Ready);
+}
+
+#[test]
+fn enum_struct_variant_with_default() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Point {
+            x: u32,
+            #[new(default)]
+            y: u64,
+            #[new(default = 1 + 2)]
+            z: u8,
+        },
+    }
+
+    let res = A::new_point(1);
+    assert_eq!(res, A::Point { x: 1, y: 0, z: 3 });
+}
+
+#[test]
+fn enum_tuple_variant_with_default() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Pair(u32, #[new(default)] u64, #[new(default = 1 + 2)] u8),
+    }
+
+    let res = A::new_pair(1);
+    assert_eq!(res, A::Pair(1, 0, 3));
+}
+
+#[test]
+fn enum_struct_variant_into() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Named {
+            #[new(into)]
+            name: String,
+        },
+    }
+
+    let res = A::new_named("abc");
+    assert_eq!(
+        res,
+        A::Named {
+            name: "abc".to_owned()
+        }
+    );
+}
+
+#[test]
+fn enum_tuple_variant_into() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Named(#[new(into)] String),
+    }
+
+    let res = A::new_named("abc");
+    assert_eq!(res, A::Named("abc".to_owned()));
+}
+
+#[test]
+fn enum_auto_defaults() {
+    #[derive(Debug, PartialEq, New)]
+    enum A<T> {
+        Marker { x: PhantomData<T>, y: () },
+        Value(T),
+    }
+
+    let res: A<u32> = A::new_marker();
+    assert_eq!(
+        res,
+        A::Marker {
+            x: PhantomData,
+            y: ()
+        }
+    );
+    assert_eq!(A::new_value(1u32), A::Value(1));
+}
+
+#[test]
+fn enum_rename_variant() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        #[new(rename = "origin")]
+        Point { x: u32, y: u64 },
+        Pair(u32, u64),
+    }
+
+    assert_eq!(A::origin(1, 2), A::Point { x: 1, y: 2 });
+    assert_eq!(A::new_pair(1, 2), A::Pair(1, 2));
+}
+
+#[test]
+fn enum_with_lifetimes_and_generics() {
+    #[derive(Debug, PartialEq, New)]
+    enum A<'a, T> {
+        Borrowed(&'a T),
+        Owned { value: T },
+    }
+
+    let x = 1u64;
+    assert_eq!(A::new_borrowed(&x), A::Borrowed(&1));
+    assert_eq!(A::<u64>::new_owned(2), A::Owned { value: 2 });
+}
+
+#[test]
+fn enum_private_new() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(pub = false)]
+    enum A {
+        Single(u32),
+    }
+
+    assert_eq!(A::new_single(1), A::Single(1));
+}
+
+#[test]
+fn enum_const_new() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(const = true)]
+    enum A {
+        Empty,
+        Single(u32),
+        Point {
+            x: u32,
+            #[new(default = 1 + 2)]
+            y: u64,
+        },
+    }
+
+    const EMPTY: A = A::new_empty();
+    const SINGLE: A = A::new_single(1);
+    const POINT: A = A::new_point(1);
+    assert_eq!(EMPTY, A::Empty);
+    assert_eq!(SINGLE, A::Single(1));
+    assert_eq!(POINT, A::Point { x: 1, y: 3 });
+}
@@ -0,0 +1,129 @@
+use inew::New;
+
+#[test]
+fn struct_from_tuple() {
+    #[derive(New)]
+    #[new(from)]
+    struct A {
+        x: u32,
+        y: String,
+        #[new(default = 3)]
+        z: u64,
+    }
+
+    let res: A = (1, "abc".to_string()).into();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, "abc");
+    assert_eq!(res.z, 3);
+}
+
+#[test]
+fn tuple_struct_from_tuple() {
+    #[derive(New)]
+    #[new(from)]
+    struct A(u32, String, #[new(default = 3)] u64);
+
+    let res = A::from((1, "abc".to_string()));
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, "abc");
+    assert_eq!(res.2, 3);
+}
+
+#[test]
+fn struct_from_single_argument() {
+    #[derive(New)]
+    #[new(from)]
+    struct A {
+        x: u32,
+        #[new(default)]
+        y: Vec<u8>,
+    }
+
+    let res: A = 1.into();
+    assert_eq!(res.x, 1);
+    assert!(res.y.is_empty());
+}
+
+#[test]
+fn tuple_struct_from_single_argument() {
+    #[derive(New)]
+    #[new(from)]
+    struct A(u32);
+
+    let res = A::from(1);
+    assert_eq!(res.0, 1);
+}
+
+#[test]
+fn struct_from_with_generics_and_lifetimes() {
+    #[derive(New)]
+    #[new(from)]
+    struct A<'a, T>
+    where
+        T: Clone,
+    {
+        x: &'a T,
+        y: T,
+    }
+
+    let value = 1u32;
+    let res = A::from((&value, 2));
+    assert_eq!(*res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn tuple_struct_from_with_generics_and_lifetimes() {
+    #[derive(New)]
+    #[new(from)]
+    struct A<'a, T: Clone>(&'a T, T);
+
+    let value = 1u32;
+    let res = A::from((&value, 2));
+    assert_eq!(*res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_from_some_and_rename() {
+    #[derive(New)]
+    #[new(from, rename = "create")]
+    struct A {
+        #[new(some)]
+        x: Option<u32>,
+    }
+
+    let res = A::from(1);
+    assert_eq!(res.x, Some(1));
+}
+
+#[test]
+fn struct_from_args_struct() {
+    #[derive(New)]
+    #[new(from, args_struct)]
+    struct A {
+        x: u32,
+        y: u32,
+    }
+
+    let res = A::from(AArgs { x: 1, y: 2 });
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn struct_from_plugs_into_into_apis() {
+    #[derive(New)]
+    #[new(from)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    fn length_squared(point: impl Into<Point>) -> i32 {
+        let point = point.into();
+        point.x * point.x + point.y * point.y
+    }
+
+    assert_eq!(length_squared((3, 4)), 25);
+}
@@ -0,0 +1,184 @@
+use inew::New;
+
+fn limit() -> u32 {
+    10
+}
+
+fn size() -> usize {
+    5
+}
+
+#[test]
+fn struct_default_ignores_parameters() {
+    #[derive(New)]
+    struct A {
+        limit: u32,
+        #[new(default = limit() * 2)]
+        doubled: u32,
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.limit, 1);
+    assert_eq!(res.doubled, 20);
+}
+
+#[test]
+fn tuple_struct_default_ignores_parameters() {
+    fn _0() -> u32 {
+        7
+    }
+
+    #[derive(New)]
+    struct A(u32, #[new(default = _0() * 2)] u32);
+
+    let res = A::new(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 14);
+}
+
+#[test]
+fn struct_default_calls_outer_function() {
+    #[derive(New)]
+    struct A {
+        size: u8,
+        #[new(default = vec![0; size()])]
+        buf: Vec<u8>,
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.size, 1);
+    assert_eq!(res.buf.len(), 5);
+}
+
+#[test]
+fn struct_value_sees_parameters() {
+    #[derive(New)]
+    struct A {
+        limit: u32,
+        #[new(default = limit() * 2)]
+        doubled: u32,
+        #[new(value = limit * 2)]
+        scaled: u32,
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.limit, 1);
+    assert_eq!(res.doubled, 20);
+    assert_eq!(res.scaled, 2);
+}
+
+#[test]
+fn struct_default_ignores_parameters_when_fallible() {
+    fn positive(value: &u32) -> Result<(), String> {
+        if *value == 0 {
+            return Err("zero".to_string());
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = String)]
+    struct A {
+        #[new(validate = positive)]
+        limit: u32,
+        #[new(default = limit() + 1)]
+        next: u32,
+    }
+
+    let res = A::new(1).unwrap();
+    assert_eq!(res.limit, 1);
+    assert_eq!(res.next, 11);
+}
+
+#[test]
+fn struct_default_ignores_parameters_with_args_struct() {
+    #[derive(New)]
+    #[new(args_struct)]
+    struct A {
+        limit: u32,
+        #[new(default = limit() + 1)]
+        next: u32,
+    }
+
+    let res = A::new(AArgs { limit: 1 });
+    assert_eq!(res.limit, 1);
+    assert_eq!(res.next, 11);
+}
+
+#[test]
+fn struct_constructor_default_sees_declared_params() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "scaled", default(doubled = limit() * factor), params(factor: u32))
+    )]
+    struct A {
+        limit: u32,
+        doubled: u32,
+    }
+
+    let res = A::scaled(1, 3);
+    assert_eq!(res.limit, 1);
+    assert_eq!(res.doubled, 30);
+
+    let res = A::new(1, 2);
+    assert_eq!(res.doubled, 2);
+}
+
+#[test]
+fn struct_field_default_ignores_declared_params() {
+    #[derive(New)]
+    #[new(
+        constructor(name = "new"),
+        constructor(name = "scaled", default(y = limit), params(limit: u32))
+    )]
+    struct A {
+        x: u32,
+        y: u32,
+        #[new(default = limit() * 2)]
+        z: u32,
+    }
+
+    let res = A::scaled(1, 3);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 3);
+    assert_eq!(res.z, 20);
+
+    let res = A::new(1, 2);
+    assert_eq!(res.y, 2);
+    assert_eq!(res.z, 20);
+}
+
+#[test]
+fn tuple_struct_field_default_ignores_declared_params() {
+    #[derive(New)]
+    #[new(constructor(name = "scaled", default(_1 = limit), params(limit: u32)))]
+    struct A(
+        u32,
+        u32,
+        #[new(default = limit() * 2)] u32,
+        #[new(value = limit + 1)] u32,
+    );
+
+    let res = A::scaled(1, 3);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 3);
+    assert_eq!(res.2, 20);
+    assert_eq!(res.3, 4);
+}
+
+#[test]
+fn struct_field_default_ignores_declared_params_with_args_struct() {
+    #[derive(New)]
+    #[new(constructor(name = "scaled", default(y = limit), params(limit: u32)), args_struct)]
+    struct A {
+        y: u32,
+        #[new(default = limit() * 2)]
+        z: u32,
+    }
+
+    let res = A::scaled(AScaledArgs { limit: 3 });
+    assert_eq!(res.y, 3);
+    assert_eq!(res.z, 20);
+}
@@ -0,0 +1,114 @@
+use inew::New;
+use std::marker::PhantomData;
+
+#[test]
+fn struct_impl_default() {
+    #[derive(New)]
+    #[new(impl_default = true)]
+    struct A {
+        #[new(default)]
+        x: u32,
+        #[new(default = 42)]
+        y: u64,
+    }
+
+    let res = A::default();
+    assert_eq!(res.x, 0);
+    assert_eq!(res.y, 42);
+}
+
+#[test]
+fn tuple_struct_impl_default() {
+    #[derive(New)]
+    #[new(impl_default = true)]
+    struct A(#[new(default)] u32, #[new(default = 42)] u64);
+
+    let res = A::default();
+    assert_eq!(res.0, 0);
+    assert_eq!(res.1, 42);
+}
+
+#[test]
+fn unit_like_struct_impl_default() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(impl_default = true)]
+    struct A;
+
+    assert_eq!(<A as Default>::default(), A);
+}
+
+#[test]
+fn struct_impl_default_auto_defaults() {
+    #[derive(New)]
+    #[new(impl_default = true)]
+    struct A<T> {
+        x: (),
+        y: PhantomData<T>,
+    }
+
+    let res: A<u32> = A::default();
+    assert_eq!(res.x, ());
+    assert_eq!(res.y, PhantomData);
+}
+
+#[test]
+fn struct_impl_default_disabled() {
+    #[derive(New)]
+    #[new(impl_default = false)]
+    struct A {
+        #[new(default = 42)]
+        x: u32,
+    }
+
+    impl Default for A {
+        fn default() -> Self {
+            Self { x: 1 }
+        }
+    }
+
+    assert_eq!(A::new().x, 42);
+    assert_eq!(A::default().x, 1);
+}
+
+#[test]
+fn struct_impl_default_renamed_constructor() {
+    #[derive(New)]
+    #[new(rename = "create", impl_default = true, pub = false)]
+    struct A {
+        #[new(default = 42)]
+        x: u32,
+    }
+
+    assert_eq!(A::create().x, 42);
+    assert_eq!(A::default().x, 42);
+}
+
+#[test]
+fn struct_impl_default_uses_zero_argument_constructor() {
+    #[derive(New)]
+    #[new(
+        impl_default = true,
+        constructor(name = "new"),
+        constructor(name = "empty", default(x = 7))
+    )]
+    struct A {
+        x: u32,
+    }
+
+    assert_eq!(A::new(1).x, 1);
+    assert_eq!(A::empty().x, 7);
+    assert_eq!(A::default().x, 7);
+}
+
+#[test]
+fn struct_impl_default_with_generics() {
+    #[derive(New)]
+    #[new(impl_default = true)]
+    struct A<T: Default> {
+        #[new(default)]
+        x: T,
+    }
+
+    let res: A<String> = A::default();
+    assert_eq!(res.x, "");
+}
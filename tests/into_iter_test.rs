@@ -0,0 +1,152 @@
+use inew::New;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+#[test]
+fn struct_into_iter_vec() {
+    #[derive(New)]
+    struct A {
+        #[new(into_iter)]
+        x: Vec<u32>,
+    }
+
+    let res = A::new([1, 2, 3]);
+    assert_eq!(res.x, vec![1, 2, 3]);
+}
+
+#[test]
+fn tuple_struct_into_iter_vec() {
+    #[derive(New)]
+    struct A(#[new(into_iter)] Vec<u32>);
+
+    let res = A::new([1, 2, 3]);
+    assert_eq!(res.0, vec![1, 2, 3]);
+}
+
+#[test]
+fn struct_into_iter_sets() {
+    #[derive(New)]
+    struct A {
+        #[new(into_iter)]
+        x: HashSet<u32>,
+        #[new(into_iter)]
+        y: BTreeSet<u32>,
+        #[new(into_iter)]
+        z: VecDeque<u32>,
+    }
+
+    let res = A::new(vec![1, 1, 2], 1..3, Some(5));
+    assert_eq!(res.x, HashSet::from([1, 2]));
+    assert_eq!(res.y, BTreeSet::from([1, 2]));
+    assert_eq!(res.z, VecDeque::from([5]));
+}
+
+#[test]
+fn tuple_struct_into_iter_sets() {
+    #[derive(New)]
+    struct A(
+        #[new(into_iter)] HashSet<u32>,
+        #[new(into_iter)] BTreeSet<u32>,
+        #[new(into_iter)] VecDeque<u32>,
+    );
+
+    let res = A::new(vec![1, 1, 2], 1..3, Some(5));
+    assert_eq!(res.0, HashSet::from([1, 2]));
+    assert_eq!(res.1, BTreeSet::from([1, 2]));
+    assert_eq!(res.2, VecDeque::from([5]));
+}
+
+#[test]
+fn struct_into_iter_maps() {
+    #[derive(New)]
+    struct A {
+        #[new(into_iter)]
+        x: HashMap<&'static str, u32>,
+        #[new(into_iter)]
+        y: BTreeMap<u32, String>,
+    }
+
+    let res = A::new([("a", 1)], vec![(1, "b".to_owned())]);
+    assert_eq!(res.x, HashMap::from([("a", 1)]));
+    assert_eq!(res.y, BTreeMap::from([(1, "b".to_owned())]));
+}
+
+#[test]
+fn tuple_struct_into_iter_maps() {
+    #[derive(New)]
+    struct A(
+        #[new(into_iter)] HashMap<&'static str, u32>,
+        #[new(into_iter)] BTreeMap<u32, String>,
+    );
+
+    let res = A::new([("a", 1)], vec![(1, "b".to_owned())]);
+    assert_eq!(res.0, HashMap::from([("a", 1)]));
+    assert_eq!(res.1, BTreeMap::from([(1, "b".to_owned())]));
+}
+
+#[test]
+fn struct_into_iter_boxed_slice() {
+    #[derive(New)]
+    struct A {
+        #[new(into_iter)]
+        x: Box<[u32]>,
+    }
+
+    let res = A::new([1, 2]);
+    assert_eq!(&*res.x, &[1, 2]);
+}
+
+#[test]
+fn struct_into_iter_explicit_item() {
+    #[derive(New)]
+    struct A {
+        #[new(into_iter = char)]
+        x: String,
+    }
+
+    let res = A::new(['a', 'b']);
+    assert_eq!(res.x, "ab");
+}
+
+#[test]
+fn tuple_struct_into_iter_explicit_item() {
+    #[derive(New)]
+    struct A(#[new(into_iter = char)] String);
+
+    let res = A::new(['a', 'b']);
+    assert_eq!(res.0, "ab");
+}
+
+#[test]
+fn struct_into_iter_with_generics() {
+    #[derive(New)]
+    struct A<T> {
+        #[new(into_iter)]
+        x: Vec<T>,
+        #[new(default)]
+        y: Vec<T>,
+    }
+
+    let res = A::new(["a", "b"]);
+    assert_eq!(res.x, vec!["a", "b"]);
+    assert!(res.y.is_empty());
+}
+
+#[test]
+fn enum_into_iter() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        List(#[new(into_iter)] Vec<u32>),
+        Set {
+            #[new(into_iter)]
+            items: BTreeSet<u32>,
+        },
+    }
+
+    assert_eq!(A::new_list(1..3), A::List(vec![1, 2]));
+    assert_eq!(
+        A::new_set([2, 1, 2]),
+        A::Set {
+            items: BTreeSet::from([1, 2])
+        }
+    );
+}
@@ -0,0 +1,128 @@
+use inew::{Builder, New};
+
+#[test]
+fn struct_parameter_name() {
+    #[derive(New)]
+    struct A {
+        #[new(name = "timeout")]
+        inner_timeout: u64,
+        x: u32,
+    }
+
+    let res = A::new(5, 1);
+    assert_eq!(res.inner_timeout, 5);
+    assert_eq!(res.x, 1);
+}
+
+#[test]
+fn tuple_struct_parameter_name() {
+    #[derive(New)]
+    struct A(#[new(name = "width")] u32, #[new(name = "height")] u32);
+
+    let res = A::new(1, 2);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_parameter_name_in_value_and_validate() {
+    fn positive(value: &u32) -> Result<(), String> {
+        if *value == 0 {
+            return Err("zero".to_string());
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = String)]
+    struct A {
+        #[new(name = "size", validate = positive)]
+        inner_size: u32,
+        #[new(value = size * 2)]
+        doubled: u32,
+    }
+
+    let res = A::new(2).unwrap();
+    assert_eq!(res.inner_size, 2);
+    assert_eq!(res.doubled, 4);
+
+    let res = A::new(0).unwrap_err();
+    assert_eq!(res, "zero");
+}
+
+#[test]
+fn tuple_struct_parameter_name_in_value() {
+    #[derive(New)]
+    struct A(#[new(name = "width")] u32, #[new(value = width * 2)] u32);
+
+    let res = A::new(2);
+    assert_eq!(res.0, 2);
+    assert_eq!(res.1, 4);
+}
+
+#[test]
+fn tuple_struct_parameter_name_in_order_and_constructor() {
+    #[derive(New)]
+    #[new(
+        order(height, width),
+        constructor(name = "new"),
+        constructor(name = "square", default(height = size), params(size: u32))
+    )]
+    struct A(#[new(name = "width")] u32, #[new(name = "height")] u32);
+
+    let res = A::new(1, 2);
+    assert_eq!(res.0, 2);
+    assert_eq!(res.1, 1);
+
+    let res = A::square(3, 3);
+    assert_eq!(res.0, 3);
+    assert_eq!(res.1, 3);
+}
+
+#[test]
+fn tuple_struct_parameter_name_in_wither() {
+    #[derive(New)]
+    struct A(u32, #[new(name = "scale", default = 1, with)] u32);
+
+    let res = A::new(2).with_scale(3);
+    assert_eq!(res.0, 2);
+    assert_eq!(res.1, 3);
+}
+
+#[test]
+fn struct_parameter_name_in_args_struct() {
+    #[derive(New)]
+    #[new(args_struct)]
+    struct A {
+        #[new(name = "timeout")]
+        inner_timeout: u64,
+    }
+
+    let res = A::new(AArgs { timeout: 5 });
+    assert_eq!(res.inner_timeout, 5);
+}
+
+#[test]
+fn tuple_struct_parameter_name_in_builder() {
+    #[derive(Builder)]
+    struct A(
+        #[new(name = "width")] u32,
+        #[new(name = "height", default = 1)] u32,
+    );
+
+    let res = A::builder().width(2).build().unwrap();
+    assert_eq!(res.0, 2);
+    assert_eq!(res.1, 1);
+}
+
+#[test]
+fn enum_parameter_name() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        B(#[new(name = "width")] u32),
+    }
+
+    let res = A::new_b(1);
+    assert_eq!(res, A::B(1));
+}
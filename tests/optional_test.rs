@@ -0,0 +1,155 @@
+use inew::New;
+use std::time::Duration;
+
+#[test]
+fn struct_optional() {
+    #[derive(New)]
+    struct A {
+        x: u32,
+        #[new(optional)]
+        timeout: Option<Duration>,
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.timeout, None);
+
+    let res = A::new(1).with_timeout(Duration::from_secs(2));
+    assert_eq!(res.timeout, Some(Duration::from_secs(2)));
+}
+
+#[test]
+fn tuple_struct_optional() {
+    #[derive(New)]
+    struct A(u32, #[new(optional)] Option<Duration>);
+
+    let res = A::new(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, None);
+
+    let res = A::new(1).with_1(Duration::from_secs(2));
+    assert_eq!(res.1, Some(Duration::from_secs(2)));
+}
+
+#[test]
+fn struct_some() {
+    #[derive(New)]
+    struct A {
+        x: u32,
+        #[new(some)]
+        timeout: Option<Duration>,
+    }
+
+    let res = A::new(1, Duration::from_secs(2));
+    assert_eq!(res.x, 1);
+    assert_eq!(res.timeout, Some(Duration::from_secs(2)));
+}
+
+#[test]
+fn tuple_struct_some() {
+    #[derive(New)]
+    struct A(u32, #[new(some)] Option<Duration>);
+
+    let res = A::new(1, Duration::from_secs(2));
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, Some(Duration::from_secs(2)));
+}
+
+#[test]
+fn struct_optional_and_some_with_generics() {
+    #[derive(New)]
+    struct A<T> {
+        #[new(some)]
+        x: Option<T>,
+        #[new(optional)]
+        y: Option<T>,
+    }
+
+    let res = A::new(1u32).with_y(2);
+    assert_eq!(res.x, Some(1));
+    assert_eq!(res.y, Some(2));
+}
+
+#[test]
+fn tuple_struct_optional_and_some_with_generics() {
+    #[derive(New)]
+    struct A<T>(#[new(some)] Option<T>, #[new(optional)] Option<T>);
+
+    let res = A::new(1u32).with_1(2);
+    assert_eq!(res.0, Some(1));
+    assert_eq!(res.1, Some(2));
+}
+
+#[test]
+fn struct_optional_full_path() {
+    #[derive(New)]
+    struct A {
+        #[new(optional)]
+        x: std::option::Option<u32>,
+    }
+
+    let res = A::new().with_x(1);
+    assert_eq!(res.x, Some(1));
+}
+
+#[test]
+fn tuple_struct_optional_full_path() {
+    #[derive(New)]
+    struct A(#[new(optional)] std::option::Option<u32>);
+
+    let res = A::new().with_0(1);
+    assert_eq!(res.0, Some(1));
+}
+
+#[test]
+fn struct_optional_const() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A {
+        #[new(some)]
+        x: Option<u32>,
+        #[new(optional)]
+        y: Option<&'static str>,
+    }
+
+    const RES: A = A::new(1).with_y("abc");
+    assert_eq!(RES.x, Some(1));
+    assert_eq!(RES.y, Some("abc"));
+}
+
+#[test]
+fn tuple_struct_optional_const() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A(
+        #[new(some)] Option<u32>,
+        #[new(optional)] Option<&'static str>,
+    );
+
+    const RES: A = A::new(1).with_1("abc");
+    assert_eq!(RES.0, Some(1));
+    assert_eq!(RES.1, Some("abc"));
+}
+
+#[test]
+fn struct_optional_with_impl_default() {
+    #[derive(New)]
+    #[new(impl_default = true)]
+    struct A {
+        #[new(optional)]
+        x: Option<String>,
+    }
+
+    let res = A::default().with_x("abc".to_string());
+    assert_eq!(res.x.as_deref(), Some("abc"));
+}
+
+#[test]
+fn tuple_struct_optional_with_impl_default() {
+    #[derive(New)]
+    #[new(impl_default = true)]
+    struct A(#[new(optional)] Option<String>);
+
+    let res = A::default().with_0("abc".to_string());
+    assert_eq!(res.0.as_deref(), Some("abc"));
+}
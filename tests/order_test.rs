@@ -0,0 +1,146 @@
+use inew::New;
+
+#[test]
+fn struct_position() {
+    #[derive(New)]
+    struct A {
+        #[new(position = 1)]
+        x: u32,
+        #[new(position = 0)]
+        y: String,
+    }
+
+    let res = A::new("abc".to_string(), 1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, "abc");
+}
+
+#[test]
+fn tuple_struct_position() {
+    #[derive(New)]
+    struct A(#[new(position = 1)] u32, #[new(position = 0)] String);
+
+    let res = A::new("abc".to_string(), 1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, "abc");
+}
+
+#[test]
+fn struct_position_skips_defaults() {
+    #[derive(New)]
+    struct A {
+        #[new(position = 2)]
+        x: u32,
+        #[new(default = 5)]
+        y: u32,
+        #[new(position = 0)]
+        z: u32,
+        #[new(position = 1)]
+        w: u32,
+    }
+
+    let res = A::new(1, 2, 3);
+    assert_eq!(res.z, 1);
+    assert_eq!(res.w, 2);
+    assert_eq!(res.x, 3);
+    assert_eq!(res.y, 5);
+}
+
+#[test]
+fn tuple_struct_position_skips_defaults() {
+    #[derive(New)]
+    struct A(
+        #[new(position = 1)] u32,
+        #[new(default = 5)] u32,
+        #[new(position = 0)] u32,
+    );
+
+    let res = A::new(1, 2);
+    assert_eq!(res.0, 2);
+    assert_eq!(res.1, 5);
+    assert_eq!(res.2, 1);
+}
+
+#[test]
+fn struct_order() {
+    #[derive(New)]
+    #[new(order(b, c, a))]
+    struct A {
+        a: u32,
+        b: u32,
+        c: u32,
+    }
+
+    let res = A::new(1, 2, 3);
+    assert_eq!(res.b, 1);
+    assert_eq!(res.c, 2);
+    assert_eq!(res.a, 3);
+}
+
+#[test]
+fn tuple_struct_order() {
+    #[derive(New)]
+    #[new(order(_1, _0))]
+    struct A(u32, u64);
+
+    let res = A::new(1, 2);
+    assert_eq!(res.0, 2);
+    assert_eq!(res.1, 1);
+}
+
+#[test]
+fn struct_order_with_conversions_and_params() {
+    #[derive(New)]
+    #[new(
+        order(items, name, suffix),
+        constructor(name = "new"),
+        constructor(name = "with_suffix", default(suffix = suffix_value.to_string()), params(suffix_value: u32))
+    )]
+    struct A {
+        #[new(into)]
+        name: String,
+        #[new(into_iter)]
+        items: Vec<u32>,
+        suffix: String,
+    }
+
+    let res = A::new([1, 2], "abc", "x".to_string());
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.items, vec![1, 2]);
+    assert_eq!(res.suffix, "x");
+
+    let res = A::with_suffix([3], "def", 7);
+    assert_eq!(res.name, "def");
+    assert_eq!(res.items, vec![3]);
+    assert_eq!(res.suffix, "7");
+}
+
+#[test]
+fn struct_order_with_from() {
+    #[derive(New)]
+    #[new(from, order(y, x))]
+    struct A {
+        x: u32,
+        y: &'static str,
+    }
+
+    let res = A::from(("abc", 1));
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, "abc");
+}
+
+#[test]
+fn enum_position() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        B {
+            #[new(position = 1)]
+            x: u32,
+            #[new(position = 0)]
+            y: &'static str,
+        },
+    }
+
+    let res = A::new_b("abc", 1);
+    assert_eq!(res, A::B { x: 1, y: "abc" });
+}
@@ -0,0 +1,144 @@
+use inew::New;
+
+#[test]
+fn struct_repeat() {
+    #[derive(New)]
+    struct A {
+        x: u32,
+        #[new(repeat = 7)]
+        y: [u8; 4],
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, [7; 4]);
+}
+
+#[test]
+fn tuple_struct_repeat() {
+    #[derive(New)]
+    struct A(u32, #[new(repeat = const { None })] [Option<String>; 2]);
+
+    let res = A::new(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, [None, None]);
+}
+
+#[test]
+fn struct_repeat_const() {
+    const SIZE: usize = 3;
+
+    #[derive(New)]
+    #[new(const = true)]
+    struct A<const N: usize> {
+        #[new(repeat = -1)]
+        x: [i8; SIZE],
+        #[new(repeat = [0; 2])]
+        y: [[u8; 2]; N],
+        #[new(repeat = const { String::new() })]
+        z: [String; 2],
+    }
+
+    const RES: A<2> = A::new();
+    let res = RES;
+    assert_eq!(res.x, [-1; 3]);
+    assert_eq!(res.y, [[0; 2]; 2]);
+    assert_eq!(res.z, ["", ""]);
+}
+
+#[test]
+fn tuple_struct_repeat_const() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A(u8, #[new(repeat = 1.5)] [f32; 2]);
+
+    const RES: A = A::new(1);
+    assert_eq!(RES.0, 1);
+    assert_eq!(RES.1, [1.5; 2]);
+}
+
+#[test]
+fn struct_repeat_non_copy() {
+    #[derive(New)]
+    struct A {
+        #[new(repeat = String::from("a"))]
+        x: [String; 2],
+        #[new(repeat = vec![1])]
+        y: [Vec<u8>; 3],
+    }
+
+    let res = A::new();
+    assert_eq!(res.x, ["a", "a"]);
+    assert_eq!(res.y, [[1], [1], [1]]);
+}
+
+#[test]
+fn tuple_struct_repeat_non_copy_const() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A(u8, #[new(repeat = Vec::new())] [Vec<u8>; 2]);
+
+    const RES: A = A::new(1);
+    let res = RES;
+    assert_eq!(res.0, 1);
+    assert!(res.1.iter().all(Vec::is_empty));
+}
+
+#[test]
+fn struct_from_fn() {
+    #[derive(New)]
+    struct A {
+        #[new(from_fn = |i| i * i)]
+        squares: [usize; 4],
+        #[new(from_fn = |i| i.to_string())]
+        names: [String; 2],
+    }
+
+    let res = A::new();
+    assert_eq!(res.squares, [0, 1, 4, 9]);
+    assert_eq!(res.names, ["0", "1"]);
+}
+
+#[test]
+fn tuple_struct_from_fn() {
+    #[derive(New)]
+    struct A<const N: usize>(u32, #[new(from_fn = |i| i as u32 * 2)] [u32; N]);
+
+    let res: A<3> = A::new(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, [0, 2, 4]);
+}
+
+#[test]
+fn struct_repeat_with_wither() {
+    #[derive(New)]
+    #[new(with)]
+    struct A {
+        #[new(repeat = 0)]
+        x: [u8; 2],
+    }
+
+    let res = A::new().with_x([1, 2]);
+    assert_eq!(res.x, [1, 2]);
+}
+
+#[test]
+fn struct_from_fn_const_auto() {
+    #[derive(New)]
+    #[new(const = auto)]
+    struct A {
+        #[new(repeat = 1)]
+        x: [u8; 2],
+    }
+
+    #[derive(New)]
+    #[new(const = auto)]
+    struct B {
+        #[new(from_fn = |i| i as u8)]
+        x: [u8; 2],
+    }
+
+    const RES: A = A::new();
+    assert_eq!(RES.x, [1, 1]);
+    assert_eq!(B::new().x, [0, 1]);
+}
@@ -0,0 +1,194 @@
+use inew::New;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn struct_returns_arc() {
+    #[derive(New)]
+    #[new(returns = Arc)]
+    struct A {
+        x: u32,
+        #[new(default = 2)]
+        y: u64,
+    }
+
+    let res: Arc<A> = A::new(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn tuple_struct_returns_arc() {
+    #[derive(New)]
+    #[new(returns = Arc)]
+    struct A(u32, #[new(default = 2)] u64);
+
+    let res: Arc<A> = A::new(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_returns_box_rc_and_pin_box() {
+    #[derive(New)]
+    #[new(returns = Box)]
+    struct A {
+        x: u32,
+    }
+
+    #[derive(New)]
+    #[new(returns = Rc)]
+    struct B {
+        x: u32,
+    }
+
+    #[derive(New)]
+    #[new(returns = Pin<Box>)]
+    struct C {
+        x: u32,
+    }
+
+    let a: Box<A> = A::new(1);
+    let b: Rc<B> = B::new(2);
+    let c: Pin<Box<C>> = C::new(3);
+    assert_eq!(a.x, 1);
+    assert_eq!(b.x, 2);
+    assert_eq!(c.x, 3);
+}
+
+#[test]
+fn struct_returns_arc_mutex() {
+    #[derive(New)]
+    #[new(returns = Arc<Mutex>)]
+    struct A {
+        x: u32,
+    }
+
+    let res: Arc<Mutex<A>> = A::new(1);
+    res.lock().unwrap().x += 1;
+    assert_eq!(res.lock().unwrap().x, 2);
+}
+
+#[test]
+fn struct_wrapped_arc() {
+    #[derive(New)]
+    #[new(wrapped = Arc)]
+    struct A {
+        #[new(into)]
+        x: String,
+    }
+
+    let res: A = A::new("abc");
+    assert_eq!(res.x, "abc");
+
+    let res: Arc<A> = A::new_arc("def");
+    assert_eq!(res.x, "def");
+}
+
+#[test]
+fn tuple_struct_wrapped_pin_box() {
+    #[derive(New)]
+    #[new(wrapped = Pin<Box>, const = true)]
+    struct A(u32);
+
+    const RES: A = A::new(1);
+    assert_eq!(RES.0, 1);
+
+    let res: Pin<Box<A>> = A::new_pin_box(2);
+    assert_eq!(res.0, 2);
+}
+
+#[test]
+fn struct_returns_arc_fallible() {
+    #[derive(New)]
+    #[new(returns = Arc)]
+    struct A {
+        #[new(try_into)]
+        x: u8,
+    }
+
+    let res: Arc<A> = A::new(1u32).unwrap();
+    assert_eq!(res.x, 1);
+
+    assert!(A::new(300u32).is_err());
+}
+
+#[test]
+fn tuple_struct_wrapped_box_fallible() {
+    #[derive(New)]
+    #[new(wrapped = Box)]
+    struct A(#[new(try_into)] u8);
+
+    let res: A = A::new(1u32).unwrap();
+    assert_eq!(res.0, 1);
+
+    let res: Box<A> = A::new_box(2u32).unwrap();
+    assert_eq!(res.0, 2);
+
+    assert!(A::new_box(300u32).is_err());
+}
+
+#[test]
+fn struct_returns_per_constructor() {
+    #[derive(New)]
+    #[new(
+        returns = Rc,
+        constructor(name = "new"),
+        constructor(name = "shared", returns = Arc, default(y = 5)),
+        constructor(name = "create", wrapped = Box),
+    )]
+    struct A {
+        x: u32,
+        y: u32,
+    }
+
+    let res: Rc<A> = A::new(1, 2);
+    assert_eq!((res.x, res.y), (1, 2));
+
+    let res: Arc<A> = A::shared(3);
+    assert_eq!((res.x, res.y), (3, 5));
+
+    let res: A = A::create(4, 6);
+    assert_eq!((res.x, res.y), (4, 6));
+
+    let res: Box<A> = A::create_box(7, 8);
+    assert_eq!((res.x, res.y), (7, 8));
+}
+
+#[test]
+fn enum_returns_arc() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(returns = Arc)]
+    enum A {
+        B(u32),
+        C { x: u32 },
+    }
+
+    let res: Arc<A> = A::new_b(1);
+    assert_eq!(*res, A::B(1));
+
+    let res: Arc<A> = A::new_c(2);
+    assert_eq!(*res, A::C { x: 2 });
+}
+
+#[test]
+fn struct_wrapped_with_default_and_from() {
+    #[derive(New)]
+    #[new(wrapped = Arc, impl_default = true)]
+    struct A {
+        #[new(default = 3)]
+        x: u32,
+    }
+
+    #[derive(New)]
+    #[new(wrapped = Rc, from)]
+    struct B {
+        x: u32,
+    }
+
+    assert_eq!(A::default().x, 3);
+    assert_eq!(A::new_arc().x, 3);
+    assert_eq!(B::from(1).x, 1);
+    assert_eq!(B::new_rc(2).x, 2);
+}
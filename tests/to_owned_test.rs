@@ -0,0 +1,95 @@
+use inew::{Builder, New};
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+#[test]
+fn struct_to_owned() {
+    #[derive(New)]
+    struct A {
+        #[new(to_owned)]
+        name: String,
+        #[new(to_owned)]
+        items: Vec<u32>,
+        #[new(to_owned)]
+        path: PathBuf,
+    }
+
+    let name = String::from("abc");
+    let res = A::new(&name, &[1, 2], Path::new("/tmp"));
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.items, vec![1, 2]);
+    assert_eq!(res.path, PathBuf::from("/tmp"));
+}
+
+#[test]
+fn tuple_struct_to_owned() {
+    #[derive(New)]
+    struct A(
+        #[new(to_owned)] Box<str>,
+        #[new(to_owned)] Box<[u8]>,
+        #[new(to_owned)] OsString,
+        #[new(to_owned)] CString,
+    );
+
+    let res = A::new("abc", &[1, 2], OsStr::new("def"), c"ghi");
+    assert_eq!(&*res.0, "abc");
+    assert_eq!(&*res.1, [1, 2]);
+    assert_eq!(res.2, "def");
+    assert_eq!(res.3.as_c_str(), c"ghi" as &CStr);
+}
+
+#[test]
+fn struct_to_owned_with_generics() {
+    #[derive(New)]
+    struct A<T: Clone> {
+        #[new(to_owned)]
+        items: Vec<T>,
+    }
+
+    fn build<T: Clone>(items: &[T]) -> A<T> {
+        A::new(items)
+    }
+
+    let res = build(&["a", "b"]);
+    assert_eq!(res.items, vec!["a", "b"]);
+}
+
+#[test]
+fn struct_to_owned_from_and_args_struct() {
+    #[derive(New)]
+    #[new(from)]
+    struct A {
+        #[new(to_owned)]
+        name: String,
+    }
+
+    #[derive(New)]
+    #[new(args_struct)]
+    struct B {
+        #[new(to_owned)]
+        name: String,
+        id: u32,
+    }
+
+    let res = A::from("abc");
+    assert_eq!(res.name, "abc");
+
+    let res = B::new(BArgs::new("def", 1));
+    assert_eq!(res.name, "def");
+    assert_eq!(res.id, 1);
+}
+
+#[test]
+fn struct_builder_to_owned() {
+    #[derive(Builder)]
+    struct A {
+        #[new(to_owned)]
+        name: String,
+        #[new(to_owned)]
+        items: Vec<u8>,
+    }
+
+    let res = A::builder().name("abc").items(&[1]).build().unwrap();
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.items, vec![1]);
+}
@@ -0,0 +1,202 @@
+use inew::New;
+use std::num::{NonZeroU32, TryFromIntError};
+
+#[test]
+fn struct_try_into() {
+    #[derive(Debug, New)]
+    struct A {
+        #[new(try_into)]
+        x: u16,
+    }
+
+    let res = A::new(1u64).unwrap();
+    assert_eq!(res.x, 1);
+    assert!(A::new(u64::MAX).is_err());
+}
+
+#[test]
+fn tuple_struct_try_into() {
+    #[derive(Debug, New)]
+    struct A(#[new(try_into)] u16);
+
+    let res = A::new(1u64).unwrap();
+    assert_eq!(res.0, 1);
+    assert!(A::new(u64::MAX).is_err());
+}
+
+#[test]
+fn struct_try_into_single_error_type() {
+    #[derive(Debug, New)]
+    struct A {
+        x: u32,
+        #[new(try_into)]
+        y: u16,
+    }
+
+    let res: Result<A, TryFromIntError> = A::new(1, 2i64);
+    let res = res.unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn struct_try_into_multiple_fields() {
+    #[derive(Debug, New)]
+    struct A {
+        #[new(try_into)]
+        port: u16,
+        name: &'static str,
+        #[new(try_into)]
+        count: NonZeroU32,
+    }
+
+    let res = A::new(8080u64, "abc", 3u32).unwrap();
+    assert_eq!(res.port, 8080);
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.count.get(), 3);
+
+    let error = A::new(u64::MAX, "abc", 3u32).unwrap_err();
+    assert!(matches!(error, ANewError::Port(_)));
+
+    let error = A::new(8080u64, "abc", 0u32).unwrap_err();
+    assert!(matches!(error, ANewError::Count(_)));
+}
+
+#[test]
+fn tuple_struct_try_into_multiple_fields() {
+    #[derive(Debug, New)]
+    struct A(#[new(try_into)] u16, #[new(try_into)] NonZeroU32);
+
+    let res = A::new(8080u64, 3u32).unwrap();
+    assert_eq!(res.0, 8080);
+    assert_eq!(res.1.get(), 3);
+
+    let error = A::new(u64::MAX, 3u32).unwrap_err();
+    assert!(matches!(error, ANewError::Field0(_)));
+
+    let error = A::new(8080u64, 0u32).unwrap_err();
+    assert!(matches!(error, ANewError::Field1(_)));
+}
+
+#[test]
+fn struct_try_into_error_display_and_source() {
+    use std::error::Error;
+
+    #[derive(Debug, New)]
+    struct A {
+        #[new(try_into)]
+        port: u16,
+        #[new(try_into)]
+        retry_count: u8,
+    }
+
+    let res = A::new(1u32, 2u32).unwrap();
+    assert_eq!(res.port, 1);
+    assert_eq!(res.retry_count, 2);
+
+    let error = A::new(1u32, 300u32).unwrap_err();
+    assert!(matches!(error, ANewError::RetryCount(_)));
+    assert!(error
+        .to_string()
+        .starts_with("failed to convert `retry_count`: "));
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn struct_try_into_custom_error() {
+    #[derive(Debug, PartialEq)]
+    struct Error;
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Self
+        }
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A {
+        #[new(try_into)]
+        x: u16,
+        #[new(try_into)]
+        y: u8,
+    }
+
+    let res = A::new(1u32, 2i64).unwrap();
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+    assert_eq!(A::new(1u32, 300i64).unwrap_err(), Error);
+}
+
+#[test]
+fn struct_try_into_with_validator() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Conversion,
+        Reserved,
+    }
+
+    impl From<TryFromIntError> for Error {
+        fn from(_: TryFromIntError) -> Self {
+            Self::Conversion
+        }
+    }
+
+    fn not_reserved(port: &u16) -> Result<(), Error> {
+        if *port < 1024 {
+            return Err(Error::Reserved);
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A {
+        #[new(try_into, validate = not_reserved)]
+        port: u16,
+    }
+
+    assert_eq!(A::new(8080u64).unwrap().port, 8080);
+    assert_eq!(A::new(80u64).unwrap_err(), Error::Reserved);
+    assert_eq!(A::new(u64::MAX).unwrap_err(), Error::Conversion);
+}
+
+#[test]
+fn struct_try_into_with_generics() {
+    #[derive(Debug, New)]
+    struct A<T> {
+        x: T,
+        #[new(try_into)]
+        y: u8,
+    }
+
+    let res = A::new("abc", 2u64).unwrap();
+    assert_eq!(res.x, "abc");
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn enum_try_into() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        Port(#[new(try_into)] u16),
+        Range {
+            #[new(try_into)]
+            min: u16,
+            #[new(try_into)]
+            max: u16,
+        },
+    }
+
+    assert_eq!(A::new_port(1u64).unwrap(), A::Port(1));
+    assert!(A::new_port(u64::MAX).is_err());
+    assert_eq!(
+        A::new_range(1u64, 2u64).unwrap(),
+        A::Range { min: 1, max: 2 }
+    );
+    assert!(matches!(
+        A::new_range(1u64, u64::MAX).unwrap_err(),
+        ARangeNewError::Max(_)
+    ));
+}
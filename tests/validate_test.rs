@@ -0,0 +1,252 @@
+use inew::New;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    EmptyName,
+    InvalidPort(u16),
+    InvalidRange,
+}
+
+fn not_empty(name: &str) -> Result<(), Error> {
+    if name.is_empty() {
+        return Err(Error::EmptyName);
+    }
+
+    Ok(())
+}
+
+fn valid_port(port: &u16) -> Result<(), Error> {
+    if *port < 1024 {
+        return Err(Error::InvalidPort(*port));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn struct_field_validator() {
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A {
+        #[new(validate = not_empty)]
+        name: String,
+    }
+
+    let res = A::new("abc".to_owned()).unwrap();
+    assert_eq!(res.name, "abc");
+    assert_eq!(A::new(String::new()).unwrap_err(), Error::EmptyName);
+}
+
+#[test]
+fn tuple_struct_field_validator() {
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A(#[new(validate = not_empty)] String);
+
+    let res = A::new("abc".to_owned()).unwrap();
+    assert_eq!(res.0, "abc");
+    assert_eq!(A::new(String::new()).unwrap_err(), Error::EmptyName);
+}
+
+#[test]
+fn struct_validators_run_in_field_order() {
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A {
+        #[new(validate = valid_port)]
+        port: u16,
+        #[new(validate = not_empty)]
+        name: String,
+    }
+
+    assert_eq!(
+        A::new(80, String::new()).unwrap_err(),
+        Error::InvalidPort(80)
+    );
+    assert_eq!(A::new(8080, String::new()).unwrap_err(), Error::EmptyName);
+
+    let res = A::new(8080, "abc".to_owned()).unwrap();
+    assert_eq!(res.port, 8080);
+    assert_eq!(res.name, "abc");
+}
+
+#[test]
+fn tuple_struct_validators_run_in_field_order() {
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A(
+        #[new(validate = valid_port)] u16,
+        #[new(validate = not_empty)] String,
+    );
+
+    assert_eq!(
+        A::new(80, String::new()).unwrap_err(),
+        Error::InvalidPort(80)
+    );
+    assert_eq!(A::new(8080, String::new()).unwrap_err(), Error::EmptyName);
+
+    let res = A::new(8080, "abc".to_owned()).unwrap();
+    assert_eq!(res.0, 8080);
+    assert_eq!(res.1, "abc");
+}
+
+#[test]
+fn struct_container_validator() {
+    #[derive(Debug, New)]
+    #[new(validate = Self::check, error = Error)]
+    struct A {
+        min: u32,
+        max: u32,
+    }
+
+    impl A {
+        fn check(&self) -> Result<(), Error> {
+            if self.min > self.max {
+                return Err(Error::InvalidRange);
+            }
+
+            Ok(())
+        }
+    }
+
+    let res = A::new(1, 2).unwrap();
+    assert_eq!(res.min, 1);
+    assert_eq!(res.max, 2);
+    assert_eq!(A::new(2, 1).unwrap_err(), Error::InvalidRange);
+}
+
+#[test]
+fn tuple_struct_container_validator() {
+    #[derive(Debug, New)]
+    #[new(validate = Self::check, error = Error)]
+    struct A(u32, u32);
+
+    impl A {
+        fn check(&self) -> Result<(), Error> {
+            if self.0 > self.1 {
+                return Err(Error::InvalidRange);
+            }
+
+            Ok(())
+        }
+    }
+
+    let res = A::new(1, 2).unwrap();
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+    assert_eq!(A::new(2, 1).unwrap_err(), Error::InvalidRange);
+}
+
+#[test]
+fn struct_field_validators_run_before_container_validator() {
+    #[derive(Debug, New)]
+    #[new(validate = Self::check, error = Error)]
+    struct A {
+        #[new(validate = valid_port)]
+        port: u16,
+    }
+
+    impl A {
+        fn check(&self) -> Result<(), Error> {
+            if self.port != 443 {
+                return Err(Error::InvalidRange);
+            }
+
+            Ok(())
+        }
+    }
+
+    assert_eq!(A::new(80).unwrap_err(), Error::InvalidPort(80));
+    assert_eq!(A::new(8080).unwrap_err(), Error::InvalidRange);
+    assert_eq!(A::new(443).unwrap_err(), Error::InvalidPort(443));
+}
+
+#[test]
+fn struct_validator_with_into_and_default() {
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A {
+        #[new(into, validate = not_empty)]
+        name: String,
+        #[new(default = 8080, validate = valid_port)]
+        port: u16,
+    }
+
+    let res = A::new("abc").unwrap();
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.port, 8080);
+    assert_eq!(A::new("").unwrap_err(), Error::EmptyName);
+}
+
+#[test]
+fn struct_validator_error_conversion() {
+    #[derive(Debug, PartialEq)]
+    struct WrappedError(Error);
+
+    impl From<Error> for WrappedError {
+        fn from(error: Error) -> Self {
+            Self(error)
+        }
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = WrappedError)]
+    struct A {
+        #[new(validate = not_empty)]
+        name: String,
+    }
+
+    assert_eq!(A::new("abc".to_owned()).unwrap().name, "abc");
+    assert_eq!(
+        A::new(String::new()).unwrap_err(),
+        WrappedError(Error::EmptyName)
+    );
+}
+
+#[test]
+fn struct_validator_with_generics() {
+    fn positive<T: PartialOrd + Default>(value: &T) -> Result<(), Error> {
+        if *value <= T::default() {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = Error)]
+    struct A<T: PartialOrd + Default> {
+        #[new(validate = positive)]
+        x: T,
+    }
+
+    assert_eq!(A::new(1u32).unwrap().x, 1);
+    assert_eq!(A::new(0i64).unwrap_err(), Error::InvalidRange);
+}
+
+#[test]
+fn enum_validators() {
+    #[derive(Debug, PartialEq, New)]
+    #[new(validate = Self::check, error = Error)]
+    enum A {
+        Named(#[new(validate = not_empty)] String),
+        Range { min: u32, max: u32 },
+    }
+
+    impl A {
+        fn check(&self) -> Result<(), Error> {
+            match self {
+                Self::Range { min, max } if min > max => Err(Error::InvalidRange),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    assert_eq!(
+        A::new_named("abc".to_owned()),
+        Ok(A::Named("abc".to_owned()))
+    );
+    assert_eq!(A::new_named(String::new()), Err(Error::EmptyName));
+    assert_eq!(A::new_range(1, 2), Ok(A::Range { min: 1, max: 2 }));
+    assert_eq!(A::new_range(2, 1), Err(Error::InvalidRange));
+}
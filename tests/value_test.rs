@@ -0,0 +1,188 @@
+use inew::New;
+use std::time::{Duration, Instant};
+
+#[test]
+fn struct_value() {
+    #[derive(New)]
+    struct A {
+        name: String,
+        #[new(value = name.len())]
+        name_len: usize,
+    }
+
+    let res = A::new("abc".to_string());
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.name_len, 3);
+}
+
+#[test]
+fn tuple_struct_value() {
+    #[derive(New)]
+    struct A(String, #[new(value = _0.len())] usize);
+
+    let res = A::new("abc".to_string());
+    assert_eq!(res.0, "abc");
+    assert_eq!(res.1, 3);
+}
+
+#[test]
+fn struct_value_before_parameter() {
+    #[derive(New)]
+    struct A {
+        #[new(value = name.to_uppercase())]
+        normalized: String,
+        name: String,
+    }
+
+    let res = A::new("abc".to_string());
+    assert_eq!(res.normalized, "ABC");
+    assert_eq!(res.name, "abc");
+}
+
+#[test]
+fn tuple_struct_value_before_parameter() {
+    #[derive(New)]
+    struct A(#[new(value = _1.to_uppercase())] String, String);
+
+    let res = A::new("abc".to_string());
+    assert_eq!(res.0, "ABC");
+    assert_eq!(res.1, "abc");
+}
+
+#[test]
+fn struct_value_uses_earlier_values() {
+    #[derive(New)]
+    struct A {
+        items: Vec<u32>,
+        #[new(value = items.iter().sum())]
+        sum: u32,
+        #[new(value = sum * 2)]
+        double: u32,
+    }
+
+    let res = A::new(vec![1, 2, 3]);
+    assert_eq!(res.items, vec![1, 2, 3]);
+    assert_eq!(res.sum, 6);
+    assert_eq!(res.double, 12);
+}
+
+#[test]
+fn tuple_struct_value_uses_earlier_values() {
+    #[derive(New)]
+    struct A(
+        Vec<u32>,
+        #[new(value = _0.iter().sum())] u32,
+        #[new(value = _1 * 2)] u32,
+    );
+
+    let res = A::new(vec![1, 2, 3]);
+    assert_eq!(res.0, vec![1, 2, 3]);
+    assert_eq!(res.1, 6);
+    assert_eq!(res.2, 12);
+}
+
+#[test]
+fn struct_value_after_conversion() {
+    #[derive(New)]
+    struct A {
+        #[new(into)]
+        name: String,
+        #[new(into_iter)]
+        items: Vec<u32>,
+        #[new(value = name.len() + items.len())]
+        total: usize,
+    }
+
+    let res = A::new("abc", [1, 2]);
+    assert_eq!(res.name, "abc");
+    assert_eq!(res.items, vec![1, 2]);
+    assert_eq!(res.total, 5);
+}
+
+#[test]
+fn struct_value_with_instant() {
+    #[derive(New)]
+    struct A {
+        timeout: Duration,
+        #[new(value = Instant::now() + timeout)]
+        deadline: Instant,
+    }
+
+    let before = Instant::now();
+    let res = A::new(Duration::from_secs(5));
+    assert_eq!(res.timeout, Duration::from_secs(5));
+    assert!(res.deadline >= before + Duration::from_secs(5));
+}
+
+#[test]
+fn struct_value_with_validation() {
+    fn non_empty(name: &str) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("name is empty".to_string());
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, New)]
+    #[new(error = String)]
+    struct A {
+        name: String,
+        #[new(value = name.trim().to_string(), validate = non_empty)]
+        trimmed: String,
+    }
+
+    let res = A::new(" abc ".to_string()).unwrap();
+    assert_eq!(res.name, " abc ");
+    assert_eq!(res.trimmed, "abc");
+
+    let res = A::new("   ".to_string()).unwrap_err();
+    assert_eq!(res, "name is empty");
+}
+
+#[test]
+fn struct_value_const() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A {
+        x: u32,
+        #[new(value = x * 2)]
+        y: u32,
+    }
+
+    const RES: A = A::new(2);
+    assert_eq!(RES.x, 2);
+    assert_eq!(RES.y, 4);
+}
+
+#[test]
+fn tuple_struct_value_const() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A(u32, #[new(value = _0 * 2)] u32);
+
+    const RES: A = A::new(2);
+    assert_eq!(RES.0, 2);
+    assert_eq!(RES.1, 4);
+}
+
+#[test]
+fn enum_value() {
+    #[derive(Debug, PartialEq, New)]
+    enum A {
+        B {
+            name: String,
+            #[new(value = name.len())]
+            len: usize,
+        },
+    }
+
+    let res = A::new_b("abc".to_string());
+    assert_eq!(
+        res,
+        A::B {
+            name: "abc".to_string(),
+            len: 3
+        }
+    );
+}
@@ -0,0 +1,166 @@
+use inew::New;
+
+#[test]
+fn struct_vis_crate() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(vis = "pub(crate)")]
+        pub struct A {
+            pub x: u32,
+        }
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.x, 1);
+}
+
+#[test]
+fn tuple_struct_vis_crate() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(vis = "pub(crate)")]
+        pub struct A(pub u32);
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.0, 1);
+}
+
+#[test]
+fn struct_vis_super() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(vis = "pub(super)")]
+        pub struct A {
+            pub x: u32,
+        }
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.x, 1);
+}
+
+#[test]
+fn struct_vis_in_path() {
+    mod outer {
+        pub mod inner {
+            use inew::New;
+
+            #[derive(New)]
+            #[new(vis = "pub(in super)")]
+            pub struct A {
+                pub x: u32,
+            }
+        }
+
+        pub fn create() -> inner::A {
+            inner::A::new(1)
+        }
+    }
+
+    let res = outer::create();
+    assert_eq!(res.x, 1);
+}
+
+#[test]
+fn struct_vis_inherit() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(vis = inherit)]
+        pub(super) struct A {
+            pub(super) x: u32,
+        }
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.x, 1);
+}
+
+#[test]
+fn tuple_struct_vis_inherit() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(vis = inherit)]
+        pub(super) struct A(pub(super) u32);
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.0, 1);
+}
+
+#[test]
+fn struct_vis_private() {
+    #[derive(New)]
+    #[new(vis = "")]
+    struct A {
+        x: u32,
+    }
+
+    let res = A::new(1);
+    assert_eq!(res.x, 1);
+}
+
+#[test]
+fn struct_vis_per_constructor() {
+    mod inner {
+        use inew::New;
+
+        #[derive(New)]
+        #[new(
+            vis = "pub(crate)",
+            constructor(name = "new"),
+            constructor(name = "zero", vis = inherit, default(x))
+        )]
+        pub(super) struct A {
+            pub(super) x: u32,
+        }
+    }
+
+    let res = inner::A::new(1);
+    assert_eq!(res.x, 1);
+
+    let res = inner::A::zero();
+    assert_eq!(res.x, 0);
+}
+
+#[test]
+fn enum_vis_inherit() {
+    mod inner {
+        use inew::New;
+
+        #[derive(Debug, PartialEq, New)]
+        #[new(vis = inherit)]
+        pub(super) enum A {
+            B(u32),
+        }
+    }
+
+    let res = inner::A::new_b(1);
+    assert_eq!(res, inner::A::B(1));
+}
+
+#[test]
+fn struct_vis_with_builder() {
+    mod inner {
+        use inew::Builder;
+
+        #[derive(Builder)]
+        #[new(vis = inherit)]
+        pub(super) struct A {
+            pub(super) x: u32,
+        }
+    }
+
+    let res = inner::A::builder().x(1).build().unwrap();
+    assert_eq!(res.x, 1);
+}
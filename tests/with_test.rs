@@ -0,0 +1,259 @@
+use inew::New;
+
+#[test]
+fn struct_with_on_field() {
+    #[derive(New)]
+    struct A {
+        x: u32,
+        #[new(default = 5, with)]
+        y: u64,
+    }
+
+    let res = A::new(1).with_y(7);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 7);
+}
+
+#[test]
+fn tuple_struct_with_on_field() {
+    #[derive(New)]
+    struct A(u32, #[new(default = 5, with)] u64);
+
+    let res = A::new(1).with_1(7);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 7);
+}
+
+#[test]
+fn struct_with_on_container() {
+    #[derive(New)]
+    #[new(with)]
+    struct A {
+        x: u32,
+        #[new(default)]
+        y: Vec<u8>,
+        #[new(default = "abc".to_string())]
+        z: String,
+    }
+
+    let res = A::new(1).with_y(vec![2]).with_z("def".to_string());
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, vec![2]);
+    assert_eq!(res.z, "def");
+}
+
+#[test]
+fn tuple_struct_with_on_container() {
+    #[derive(New)]
+    #[new(with)]
+    struct A(
+        u32,
+        #[new(default)] Vec<u8>,
+        #[new(default = "abc".to_string())] String,
+    );
+
+    let res = A::new(1).with_1(vec![2]).with_2("def".to_string());
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, vec![2]);
+    assert_eq!(res.2, "def");
+}
+
+#[test]
+fn struct_with_keeps_defaults() {
+    #[derive(New)]
+    #[new(with)]
+    struct A {
+        #[new(default = 5)]
+        x: u32,
+        #[new(default = 6)]
+        y: u32,
+    }
+
+    let res = A::new().with_x(1);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 6);
+}
+
+#[test]
+fn tuple_struct_with_keeps_defaults() {
+    #[derive(New)]
+    #[new(with)]
+    struct A(#[new(default = 5)] u32, #[new(default = 6)] u32);
+
+    let res = A::new().with_0(1);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 6);
+}
+
+#[test]
+fn struct_with_into() {
+    #[derive(New)]
+    struct A {
+        #[new(default, into, with)]
+        x: String,
+    }
+
+    let res = A::new().with_x("abc");
+    assert_eq!(res.x, "abc");
+}
+
+#[test]
+fn tuple_struct_with_into() {
+    #[derive(New)]
+    struct A(#[new(default, into, with)] String);
+
+    let res = A::new().with_0("abc");
+    assert_eq!(res.0, "abc");
+}
+
+#[test]
+fn struct_with_into_iter() {
+    #[derive(New)]
+    struct A {
+        #[new(default, into_iter, with)]
+        x: Vec<u8>,
+    }
+
+    let res = A::new().with_x([1, 2]);
+    assert_eq!(res.x, vec![1, 2]);
+}
+
+#[test]
+fn tuple_struct_with_into_iter() {
+    #[derive(New)]
+    struct A(#[new(default, into_iter, with)] Vec<u8>);
+
+    let res = A::new().with_0([1, 2]);
+    assert_eq!(res.0, vec![1, 2]);
+}
+
+#[test]
+fn struct_with_const() {
+    #[derive(New)]
+    #[new(const = true, with)]
+    struct A {
+        x: u32,
+        #[new(default = 5)]
+        y: u64,
+        #[new(default = None)]
+        z: Option<&'static str>,
+    }
+
+    const RES: A = A::new(1).with_y(7).with_z(Some("abc"));
+    assert_eq!(RES.x, 1);
+    assert_eq!(RES.y, 7);
+    assert_eq!(RES.z, Some("abc"));
+}
+
+#[test]
+fn tuple_struct_with_const() {
+    #[derive(New)]
+    #[new(const = true, with)]
+    struct A(
+        u32,
+        #[new(default = 5)] u64,
+        #[new(default = None)] Option<&'static str>,
+    );
+
+    const RES: A = A::new(1).with_1(7).with_2(Some("abc"));
+    assert_eq!(RES.0, 1);
+    assert_eq!(RES.1, 7);
+    assert_eq!(RES.2, Some("abc"));
+}
+
+#[test]
+fn struct_with_const_non_trivial_field() {
+    #[derive(New)]
+    #[new(const = true, with)]
+    struct A {
+        #[new(default = 5)]
+        x: u64,
+        #[new(default = String::new())]
+        y: String,
+    }
+
+    const RES: A = A::new().with_x(7);
+    let res = RES.with_y("abc".to_string());
+    assert_eq!(res.x, 7);
+    assert_eq!(res.y, "abc");
+}
+
+#[test]
+fn tuple_struct_with_const_non_trivial_field() {
+    #[derive(New)]
+    #[new(const = true, with)]
+    struct A(
+        #[new(default = 5)] u64,
+        #[new(default = String::new())] String,
+    );
+
+    const RES: A = A::new().with_0(7);
+    let res = RES.with_1("abc".to_string());
+    assert_eq!(res.0, 7);
+    assert_eq!(res.1, "abc");
+}
+
+#[test]
+fn struct_with_const_and_into_wither() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A {
+        #[new(default = 5, into, with)]
+        x: u64,
+    }
+
+    const RES: A = A::new();
+    let res = RES.with_x(7u8);
+    assert_eq!(RES.x, 5);
+    assert_eq!(res.x, 7);
+}
+
+#[test]
+fn tuple_struct_with_const_and_into_wither() {
+    #[derive(New)]
+    #[new(const = true)]
+    struct A(#[new(default = 5, into, with)] u64);
+
+    const RES: A = A::new();
+    let res = RES.with_0(7u8);
+    assert_eq!(RES.0, 5);
+    assert_eq!(res.0, 7);
+}
+
+#[test]
+fn struct_with_generics() {
+    #[derive(New)]
+    #[new(with)]
+    struct A<T: Default> {
+        x: u32,
+        #[new(default)]
+        y: T,
+    }
+
+    let res = A::<u64>::new(1).with_y(2);
+    assert_eq!(res.x, 1);
+    assert_eq!(res.y, 2);
+}
+
+#[test]
+fn tuple_struct_with_generics() {
+    #[derive(New)]
+    #[new(with)]
+    struct A<T: Default>(u32, #[new(default)] T);
+
+    let res = A::<u64>::new(1).with_1(2);
+    assert_eq!(res.0, 1);
+    assert_eq!(res.1, 2);
+}
+
+#[test]
+fn struct_with_raw_identifier() {
+    #[derive(New)]
+    struct A {
+        #[new(default = 1, with)]
+        r#type: u32,
+    }
+
+    let res = A::new().with_type(2);
+    assert_eq!(res.r#type, 2);
+}